@@ -0,0 +1,58 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+
+/// A colour the user can tag saves with, to tell them apart at a glance (see `SaveMetadata`).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum ColorTag {
+    /// Red.
+    Red,
+    /// Orange.
+    Orange,
+    /// Yellow.
+    Yellow,
+    /// Green.
+    Green,
+    /// Blue.
+    Blue,
+    /// Purple.
+    Purple,
+}
+
+impl ColorTag {
+    /// Every `ColorTag`, in the order they are shown in.
+    pub const ALL: [Self; 6] = [
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Green,
+        Self::Blue,
+        Self::Purple,
+    ];
+
+    /// Get the name of this `ColorTag`, as shown to the user.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Red => "Red",
+            Self::Orange => "Orange",
+            Self::Yellow => "Yellow",
+            Self::Green => "Green",
+            Self::Blue => "Blue",
+            Self::Purple => "Purple",
+        }
+    }
+
+    /// Get the colour this `ColorTag` is drawn with.
+    pub const fn color(self) -> Color32 {
+        match self {
+            Self::Red => Color32::from_rgb(220, 60, 60),
+            Self::Orange => Color32::from_rgb(235, 140, 40),
+            Self::Yellow => Color32::from_rgb(225, 200, 50),
+            Self::Green => Color32::from_rgb(70, 180, 80),
+            Self::Blue => Color32::from_rgb(60, 120, 220),
+            Self::Purple => Color32::from_rgb(150, 80, 200),
+        }
+    }
+}
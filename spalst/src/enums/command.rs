@@ -0,0 +1,70 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use clap::Subcommand;
+use std::path::PathBuf;
+
+/// A subcommand of `ArgsParser`. When one is given, it is run instead of opening the window.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Export a stored `Playthrough` into an archive.
+    #[command(about = "Export a playthrough (or one of its branches) into a portable archive.")]
+    Export {
+        /// The name of the `Playthrough`.
+        #[arg(help = "The name of the playthrough to export.")]
+        name: String,
+        /// Where to write the archive.
+        #[arg(help = "Where to write the archive.")]
+        output: PathBuf,
+        /// The index of a save. If given, only the branch from the root save to it is exported.
+        #[arg(
+            long,
+            help = "Only export the branch from the root save to the save with this number."
+        )]
+        save: Option<usize>,
+    },
+    /// Import a `Playthrough` from an archive.
+    #[command(about = "Import a playthrough from an archive.")]
+    Import {
+        /// The archive to import.
+        #[arg(help = "The archive to import.")]
+        archive: PathBuf,
+        /// The name to store the `Playthrough` under, instead of the one in the archive.
+        #[arg(
+            long,
+            help = "Store the playthrough under this name, instead of the one it was exported with."
+        )]
+        name: Option<String>,
+    },
+    /// Upgrade stored `Playthrough`s to the current format version.
+    #[command(about = "Upgrade stored playthroughs to the current file format.")]
+    Migrate {
+        /// The name of the `Playthrough` to upgrade. Every one is upgraded if not given.
+        #[arg(help = "The name of the playthrough to upgrade. If not given, every playthrough is upgraded.")]
+        name: Option<String>,
+        /// Only report what would change, without writing anything.
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Only report what would change, without writing anything."
+        )]
+        dry_run: bool,
+    },
+    /// Check every `Playthrough` in a directory for structural problems.
+    #[command(about = "Check every playthrough in a directory for structural problems.")]
+    Verify {
+        /// The directory to check, instead of the one the game stores `Playthrough`s in.
+        #[arg(
+            long,
+            help = "Check the playthroughs in this directory, instead of the ones of the game."
+        )]
+        dir: Option<PathBuf>,
+        /// Whether to repair the problems that can be repaired safely.
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Repair the problems that can be repaired without losing any saves. The damaged files are kept as backups."
+        )]
+        repair: bool,
+    },
+}
@@ -0,0 +1,12 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+/// What the user chose in a `ConfirmDialog`. Either way, the value the dialog was opened with is
+/// handed back.
+#[derive(Clone, Debug)]
+pub enum Confirmation<T> {
+    /// The user confirmed.
+    Confirmed(T),
+    /// The user cancelled.
+    Cancelled(T),
+}
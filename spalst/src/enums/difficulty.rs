@@ -0,0 +1,39 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use serde::{Deserialize, Serialize};
+
+/// How hard the game is, chosen when a `Playthrough` is created (see `PlaythroughSettings`).
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Difficulty {
+    /// For players who are here for the story.
+    Easy,
+    /// The game as it is meant to be played.
+    #[default]
+    Normal,
+    /// For players who want every mistake to hurt.
+    Hard,
+}
+
+impl Difficulty {
+    /// Every `Difficulty`, from the easiest to the hardest.
+    pub const ALL: [Self; 3] = [Self::Easy, Self::Normal, Self::Hard];
+
+    /// Get the name of this `Difficulty`, as shown to the user.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+        }
+    }
+
+    /// Describe this `Difficulty` to the user.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Easy => "For players who are here for the story.",
+            Self::Normal => "The game as it is meant to be played.",
+            Self::Hard => "For players who want every mistake to hurt.",
+        }
+    }
+}
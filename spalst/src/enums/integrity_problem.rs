@@ -0,0 +1,87 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use core::fmt::{self, Display, Formatter};
+
+/// A structural problem found in a stored `Playthrough` by `Playthrough::verify()`.
+///
+/// Saves are referred to by the index they were stored with.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IntegrityProblem {
+    /// More than one save has no parent. Holds the indexes of all of them.
+    MultipleRoots(Vec<usize>),
+    /// The root save points to an index that no save was stored with.
+    BadRoot(usize),
+    /// Following the parents of these saves leads back to where it started.
+    Cycle(Vec<usize>),
+    /// The parent of a save points to an index that no save was stored with.
+    DanglingParent {
+        /// The index of the save.
+        save: usize,
+        /// The index its parent points to.
+        parent: usize,
+    },
+    /// More than one save was stored with this index, or it is 0.
+    InvalidIndex(usize),
+    /// The save with this index doesn't match its checksum, so it was changed after it was stored.
+    ChecksumMismatch(usize),
+    /// A save was born before its parent was.
+    BornBeforeParent {
+        /// The index of the save.
+        save: usize,
+        /// The index of its parent.
+        parent: usize,
+    },
+}
+
+impl IntegrityProblem {
+    /// Check whether `Playthrough::repair()` can fix this problem.
+    ///
+    /// Only problems that can be fixed without losing any saves are repaired. Saves that are cut
+    /// off from the tree are attached to the root save again, so those can only be fixed if the
+    /// root save is known.
+    pub const fn is_repairable(&self) -> bool {
+        match *self {
+            Self::MultipleRoots(_) | Self::BadRoot(_) | Self::Cycle(_) | Self::DanglingParent { .. } => true,
+            Self::InvalidIndex(_) | Self::ChecksumMismatch(_) | Self::BornBeforeParent { .. } => false,
+        }
+    }
+
+    /// List save indexes for display, separated by commas.
+    fn list(indexes: &[usize]) -> String {
+        indexes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+impl Display for IntegrityProblem {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match *self {
+            Self::MultipleRoots(ref roots) => write!(f, "Multiple saves have no parent: {}.", Self::list(roots)),
+            Self::BadRoot(root) => write!(f, "The root save points to the missing save {root}."),
+            Self::Cycle(ref saves) => write!(
+                f,
+                "The parents of saves {} form a cycle.",
+                Self::list(saves)
+            ),
+            Self::DanglingParent { save, parent } => {
+                write!(f, "Save {save} points to the missing parent {parent}.")
+            }
+            Self::InvalidIndex(index) => {
+                write!(f, "The index {index} is invalid or used by multiple saves.")
+            }
+            Self::ChecksumMismatch(save) => {
+                write!(f, "Save {save} doesn't match its checksum.")
+            }
+            Self::BornBeforeParent { save, parent } => {
+                write!(f, "Save {save} was created before its parent {parent}.")
+            }
+        }
+    }
+}
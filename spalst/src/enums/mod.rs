@@ -1,14 +1,40 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
+mod color_tag;
+mod command;
+mod confirmation;
+mod difficulty;
+mod integrity_problem;
 #[cfg(feature = "logging")]
 mod log_level;
+mod navigation_direction;
+mod playthrough_action;
+mod playthrough_request;
 mod request;
+mod route;
+mod save_change;
 mod save_entry;
+mod save_request;
 mod screen_manager_request;
+mod transition_kind;
+mod wizard_step;
 
+pub use color_tag::ColorTag;
+pub use command::Command;
+pub use confirmation::Confirmation;
+pub use difficulty::Difficulty;
+pub use integrity_problem::IntegrityProblem;
 #[cfg(feature = "logging")]
 pub use log_level::LogLevel;
+pub use navigation_direction::NavigationDirection;
+pub use playthrough_action::PlaythroughAction;
+pub use playthrough_request::PlaythroughRequest;
 pub use request::Request;
+pub use route::Route;
+pub use save_change::SaveChange;
 pub use save_entry::SaveEntry;
+pub use save_request::SaveRequest;
 pub use screen_manager_request::ScreenManagerRequest;
+pub use transition_kind::TransitionKind;
+pub use wizard_step::WizardStep;
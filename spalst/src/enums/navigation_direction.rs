@@ -0,0 +1,12 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+/// Which way through the history of `ScreenManager` a `Screen` was switched to. Decides which way
+/// a `Transition` slides.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NavigationDirection {
+    /// Navigating to a new `Screen`, or going forward.
+    Forward,
+    /// Going back.
+    Back,
+}
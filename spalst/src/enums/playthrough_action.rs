@@ -0,0 +1,37 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+/// An action on a stored `Playthrough` that the user started in `ManagePlaythroughsScreen`, but
+/// didn't confirm yet.
+#[derive(Clone, Debug)]
+pub enum PlaythroughAction {
+    /// Rename a `Playthrough`.
+    Rename {
+        /// The current name of the `Playthrough`.
+        name: String,
+        /// The name being typed in.
+        new_name: String,
+    },
+    /// Make a copy of a `Playthrough`.
+    Duplicate {
+        /// The name of the `Playthrough` to copy.
+        name: String,
+        /// The name of the copy being typed in.
+        new_name: String,
+    },
+    /// Export a `Playthrough` into an archive.
+    Export {
+        /// The name of the `Playthrough`.
+        name: String,
+        /// The path of the archive being typed in.
+        path: String,
+    },
+    /// Import a `Playthrough` from an archive.
+    Import {
+        /// The path of the archive being typed in.
+        path: String,
+        /// The name to store the `Playthrough` under being typed in. If empty, the name in the
+        /// archive is kept.
+        name: String,
+    },
+}
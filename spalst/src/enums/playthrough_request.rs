@@ -0,0 +1,78 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{enums::Request, structs::PlaythroughSettings};
+use std::path::PathBuf;
+
+/// A `Request` concerning the stored `Playthrough`s.
+///
+/// After any of these is handled, the `PlaythroughSummary`s in `AppState` are refreshed.
+#[derive(Debug)]
+pub enum PlaythroughRequest {
+    /// Only refresh the `PlaythroughSummary`s.
+    Refresh,
+    /// Load the `Playthrough` with this name, and make it the open one (see
+    /// `AppState::open_playthrough`).
+    ///
+    /// Failing doesn't close the app. The reason is reported in `AppState::open_error`.
+    Open(String),
+    /// Create a new `Playthrough` with a root save, store it and start playing its root save.
+    ///
+    /// Failing doesn't close the app. The reason is reported in `AppState::create_error`.
+    Create {
+        /// The name of the `Playthrough`.
+        name: String,
+        /// The rules to create it with.
+        settings: PlaythroughSettings,
+        /// The `Request`s to handle once it is created, e.g. to show the game. They are dropped
+        /// if creating it fails.
+        then: Vec<Request>,
+    },
+    /// Open the `Playthrough` with this name like `PlaythroughRequest::Open`, and continue
+    /// playing the save that was played last (see `Playthrough::resume()`).
+    ///
+    /// Failing doesn't close the app. The reason is reported in `AppState::open_error`.
+    Continue(String),
+    /// Rename a `Playthrough`.
+    ///
+    /// Failing doesn't close the app. The reason is reported in `AppState::manage_error`.
+    Rename {
+        /// The current name of the `Playthrough`.
+        name: String,
+        /// The name to give it.
+        new_name: String,
+    },
+    /// Make a copy of a `Playthrough`.
+    ///
+    /// Failing doesn't close the app. The reason is reported in `AppState::manage_error`.
+    Duplicate {
+        /// The name of the `Playthrough` to copy.
+        name: String,
+        /// The name of the copy.
+        new_name: String,
+    },
+    /// Delete the `Playthrough` with this name, along with its backups.
+    ///
+    /// Failing doesn't close the app. The reason is reported in `AppState::manage_error`.
+    Delete(String),
+    /// Export a `Playthrough` into an archive (see `PlaythroughStore::export()`).
+    ///
+    /// Failing doesn't close the app. The result is reported in `AppState::archive_status`.
+    Export {
+        /// The name of the `Playthrough`.
+        name: String,
+        /// The index of the save to export the branch of, or `None` to export every save.
+        save: Option<usize>,
+        /// Where to write the archive.
+        path: PathBuf,
+    },
+    /// Import a `Playthrough` from an archive (see `PlaythroughStore::import()`).
+    ///
+    /// Failing doesn't close the app. The result is reported in `AppState::archive_status`.
+    Import {
+        /// The archive to import.
+        path: PathBuf,
+        /// The name to store the `Playthrough` under, or `None` to keep the one in the archive.
+        name: Option<String>,
+    },
+}
@@ -1,13 +1,19 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::enums::ScreenManagerRequest;
+use crate::enums::{PlaythroughRequest, SaveRequest, ScreenManagerRequest};
 
 /// Actions that a `Screen` can request after rendering.
 #[derive(Debug)]
 pub enum Request {
     /// See documentation of `ScreenManagerRequest`.
     ScreenManager(ScreenManagerRequest),
-    /// Quit the application.
+    /// See documentation of `PlaythroughRequest`.
+    Playthrough(PlaythroughRequest),
+    /// See documentation of `SaveRequest`.
+    Save(SaveRequest),
+    /// Autosave the active save, if any (see `AppState::autosave()`).
+    Autosave,
+    /// Autosave, then quit the application.
     Quit,
 }
@@ -0,0 +1,123 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::{GameScreen, MainMenuScreen, ManagePlaythroughsScreen, NewPlaythroughScreen, SaveTreeScreen, SettingsScreen};
+use core::{
+    num::ParseIntError,
+    str::{FromStr, Split},
+};
+
+/// A `Screen` to open on startup instead of the main menu, given with `--screen` (see
+/// `AppState::open_route()`).
+///
+/// It is written as the route names of the screens on the way (see `MainMenuScreen::ROUTE` and
+/// the like), separated by `/`. Screens that need to know what to show take it after a `:`, like
+/// `playthrough:<name>/save:<index>`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Route {
+    /// The `MainMenuScreen`.
+    MainMenu,
+    /// The `SettingsScreen`.
+    Settings,
+    /// The `NewPlaythroughScreen`.
+    NewPlaythrough,
+    /// The `ManagePlaythroughsScreen`.
+    ManagePlaythroughs,
+    /// The `SaveTreeScreen` of a `Playthrough`, or the `GameScreen` playing one of its saves.
+    Playthrough {
+        /// The name of the `Playthrough`.
+        name: String,
+        /// The index of the save to play (as shown to the user), if any.
+        save: Option<usize>,
+    },
+}
+
+impl Route {
+    /// List every route, for error messages.
+    fn known_routes() -> String {
+        format!(
+            "{}, {}, {}, {}, {}:<name>, {}:<name>/{}:<index>",
+            MainMenuScreen::ROUTE,
+            SettingsScreen::ROUTE,
+            NewPlaythroughScreen::ROUTE,
+            ManagePlaythroughsScreen::ROUTE,
+            SaveTreeScreen::ROUTE,
+            SaveTreeScreen::ROUTE,
+            GameScreen::ROUTE,
+        )
+    }
+
+    /// Parse the part of a route after a `SaveTreeScreen`, if any, into the index of a save.
+    fn parse_save(segment: Option<&str>) -> Result<Option<usize>, String> {
+        let Some(segment) = segment else {
+            return Ok(None);
+        };
+        let index: &str = segment
+            .strip_prefix(GameScreen::ROUTE)
+            .and_then(|rest: &str| rest.strip_prefix(':'))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown route \"{segment}\" after \"{}\". Expected \"{}:<index>\".",
+                    SaveTreeScreen::ROUTE,
+                    GameScreen::ROUTE
+                )
+            })?;
+        index
+            .parse()
+            .map(Some)
+            .map_err(|report: ParseIntError| format!("\"{index}\" isn't the index of a save: {report}."))
+    }
+}
+
+impl FromStr for Route {
+    type Err = String;
+
+    fn from_str(route: &str) -> Result<Self, Self::Err> {
+        let mut segments: Split<'_, char> = route.split('/');
+        let first: &str = segments.next().unwrap_or_default();
+        let (screen, argument): (&str, Option<&str>) = first
+            .split_once(':')
+            .map_or((first, None), |(screen, argument): (&str, &str)| {
+                (screen, Some(argument))
+            });
+        let second: Option<&str> = segments.next();
+        if segments.next().is_some() {
+            return Err(format!("The route \"{route}\" is too long."));
+        }
+
+        let parsed: Self = match (screen, argument) {
+            (SaveTreeScreen::ROUTE, Some(name)) if !name.is_empty() => {
+                return Ok(Self::Playthrough {
+                    name: name.to_owned(),
+                    save: Self::parse_save(second)?,
+                });
+            }
+            (SaveTreeScreen::ROUTE, _) => {
+                return Err(format!(
+                    "The \"{}\" route needs the name of a playthrough, like \"{}:<name>\".",
+                    SaveTreeScreen::ROUTE,
+                    SaveTreeScreen::ROUTE
+                ));
+            }
+            (MainMenuScreen::ROUTE, None) => Self::MainMenu,
+            (SettingsScreen::ROUTE, None) => Self::Settings,
+            (NewPlaythroughScreen::ROUTE, None) => Self::NewPlaythrough,
+            (ManagePlaythroughsScreen::ROUTE, None) => Self::ManagePlaythroughs,
+            (MainMenuScreen::ROUTE | SettingsScreen::ROUTE | NewPlaythroughScreen::ROUTE | ManagePlaythroughsScreen::ROUTE, Some(_)) => {
+                return Err(format!("The \"{screen}\" route doesn't take an argument."));
+            }
+            _ => {
+                return Err(format!(
+                    "Unknown route \"{first}\". Known routes: {}.",
+                    Self::known_routes()
+                ));
+            }
+        };
+        second.map_or(Ok(parsed), |segment: &str| {
+            Err(format!(
+                "Unknown route \"{segment}\" after \"{screen}\". Only \"{}:<name>\" leads further.",
+                SaveTreeScreen::ROUTE
+            ))
+        })
+    }
+}
@@ -0,0 +1,8 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+mod def;
+#[cfg(test)]
+mod tests;
+
+pub use def::Route;
@@ -0,0 +1,50 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+//!
+//! Unit tests for `Route`.
+
+use crate::{enums::Route, structs::SettingsScreen};
+use color_eyre::eyre::{Result, eyre};
+
+/// Routes are parsed from the route names of the screens, and unknown ones are rejected.
+#[test]
+fn routes_parse() -> Result<()> {
+    assert_eq!(
+        "settings"
+            .parse::<Route>()
+            .map_err(|report: String| eyre!(report))?,
+        Route::Settings,
+        "A route without an argument must parse."
+    );
+    assert_eq!(
+        "playthrough:My World/save:3"
+            .parse::<Route>()
+            .map_err(|report: String| eyre!(report))?,
+        Route::Playthrough {
+            name: "My World".to_owned(),
+            save: Some(3),
+        },
+        "A route to a save must parse."
+    );
+    for route in [
+        "options",
+        "settings:x",
+        "settings/save:1",
+        "playthrough",
+        "playthrough:x/save:one",
+        "playthrough:x/tree",
+        "playthrough:x/save:1/more",
+    ] {
+        assert!(
+            route.parse::<Route>().is_err(),
+            "The route \"{route}\" must be rejected."
+        );
+    }
+    assert!(
+        "options"
+            .parse::<Route>()
+            .is_err_and(|report: String| report.contains(SettingsScreen::ROUTE)),
+        "An unknown route must list the known ones."
+    );
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use core::fmt::{self, Display, Formatter};
+use serde_json::Value;
+
+/// A single difference between two `Save`s, found by `Playthrough::diff()`.
+///
+/// Every change is at a path into the `Save` as it is serialized, written as a JSON pointer (for
+/// example `/metadata/tags/0`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SaveChange {
+    /// Only the newer `Save` has a value at this path.
+    Added {
+        /// Where the value is.
+        path: String,
+        /// The value the newer `Save` has.
+        value: Value,
+    },
+    /// Only the older `Save` has a value at this path.
+    Removed {
+        /// Where the value was.
+        path: String,
+        /// The value the older `Save` has.
+        value: Value,
+    },
+    /// Both `Save`s have a value at this path, but they differ.
+    Changed {
+        /// Where the values are.
+        path: String,
+        /// The value the older `Save` has.
+        before: Value,
+        /// The value the newer `Save` has.
+        after: Value,
+    },
+}
+
+impl SaveChange {
+    /// Get the path this change is at.
+    pub fn path(&self) -> &str {
+        match *self {
+            Self::Added { ref path, .. } | Self::Removed { ref path, .. } | Self::Changed { ref path, .. } => path,
+        }
+    }
+
+    /// Get the value the older `Save` has at the path of this change, if any.
+    pub const fn before(&self) -> Option<&Value> {
+        match *self {
+            Self::Added { .. } => None,
+            Self::Removed { ref value, .. } => Some(value),
+            Self::Changed { ref before, .. } => Some(before),
+        }
+    }
+
+    /// Get the value the newer `Save` has at the path of this change, if any.
+    pub const fn after(&self) -> Option<&Value> {
+        match *self {
+            Self::Added { ref value, .. } => Some(value),
+            Self::Removed { .. } => None,
+            Self::Changed { ref after, .. } => Some(after),
+        }
+    }
+}
+
+impl Display for SaveChange {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match *self {
+            Self::Added {
+                ref path,
+                ref value,
+            } => write!(f, "{path}: added {value}"),
+            Self::Removed {
+                ref path,
+                ref value,
+            } => write!(f, "{path}: removed {value}"),
+            Self::Changed {
+                ref path,
+                ref before,
+                ref after,
+            } => write!(f, "{path}: {before} -> {after}"),
+        }
+    }
+}
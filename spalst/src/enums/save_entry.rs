@@ -1,17 +1,85 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::structs::{DeletedSave, Save};
+use crate::structs::{DeletedSave, Save, SaveMetadata, TrashedSave};
+use chrono::{DateTime, Utc};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
 
 /// A save entry.
 ///
-/// Holds either a `Save` or a `DeletedSave`.
+/// Holds a `Save`, a `TrashedSave` or a `DeletedSave`.
 ///
 /// To see why that's important, check the documentation of `DeletedSave`.
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum SaveEntry {
     /// An active `Save`.
     Active(Save),
+    /// A deleted save that can still be restored (`TrashedSave`).
+    Trashed(TrashedSave),
     /// A deleted save (`DeletedSave`).
     Deleted(DeletedSave),
 }
+
+impl SaveEntry {
+    /// Check whether this entry was deleted, whether it can still be restored or not.
+    pub const fn is_deleted(&self) -> bool {
+        !matches!(*self, Self::Active(_))
+    }
+
+    /// Get the `Save` this entry keeps, whether it is in the trash or not. `None` if it was deleted
+    /// permanently, since a `DeletedSave` doesn't keep the game.
+    pub const fn save(&self) -> Option<&Save> {
+        match *self {
+            Self::Active(ref save) => Some(save),
+            Self::Trashed(ref trashed_save) => Some(trashed_save.save()),
+            Self::Deleted(_) => None,
+        }
+    }
+
+    /// Check whether this entry was made by an autosave. Permanently deleted saves don't keep
+    /// track of that.
+    pub const fn is_autosave(&self) -> bool {
+        match *self {
+            Self::Active(ref save) => save.is_autosave(),
+            Self::Trashed(ref trashed_save) => trashed_save.save().is_autosave(),
+            Self::Deleted(_) => false,
+        }
+    }
+
+    /// Get what the user wrote down about this entry, whether it was deleted or not.
+    pub const fn metadata(&self) -> &SaveMetadata {
+        match *self {
+            Self::Active(ref save) => save.metadata(),
+            Self::Trashed(ref trashed_save) => trashed_save.save().metadata(),
+            Self::Deleted(ref deleted_save) => deleted_save.metadata(),
+        }
+    }
+
+    /// Get what the user wrote down about this entry mutably, whether it was deleted or not.
+    pub const fn metadata_mut(&mut self) -> &mut SaveMetadata {
+        match *self {
+            Self::Active(ref mut save) => save.metadata_mut(),
+            Self::Trashed(ref mut trashed_save) => trashed_save.save_mut().metadata_mut(),
+            Self::Deleted(ref mut deleted_save) => deleted_save.metadata_mut(),
+        }
+    }
+
+    /// Get the playtime of this entry, whether it was deleted or not.
+    pub const fn get_playtime(&self) -> Duration {
+        match *self {
+            Self::Active(ref save) => save.get_playtime(),
+            Self::Trashed(ref trashed_save) => trashed_save.save().get_playtime(),
+            Self::Deleted(ref deleted_save) => deleted_save.get_playtime(),
+        }
+    }
+
+    /// Get the birth time of this entry, whether it was deleted or not.
+    pub const fn get_birth_time(&self) -> DateTime<Utc> {
+        match *self {
+            Self::Active(ref save) => save.get_birth_time(),
+            Self::Trashed(ref trashed_save) => trashed_save.save().get_birth_time(),
+            Self::Deleted(ref deleted_save) => deleted_save.get_birth_time(),
+        }
+    }
+}
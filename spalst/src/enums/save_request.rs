@@ -0,0 +1,49 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::Request,
+    structs::{SaveId, SaveMetadata},
+};
+
+/// A `Request` concerning the saves of the open `Playthrough` (see `AppState::open_playthrough`).
+///
+/// After any of these is handled, the open `Playthrough` is saved to disk.
+///
+/// Failing doesn't close the app. The reason is reported in `AppState::save_error`.
+#[derive(Debug)]
+pub enum SaveRequest {
+    /// Fork the save with this `SaveId`.
+    Fork(SaveId),
+    /// Delete the save with this `SaveId`.
+    Delete(SaveId),
+    /// Restore the deleted save with this `SaveId`.
+    Restore(SaveId),
+    /// Start playing a save.
+    Play {
+        /// The `SaveId` of the save.
+        id: SaveId,
+        /// The `Request`s to handle once it is being played, e.g. to show the game. They are
+        /// dropped if playing it fails.
+        then: Vec<Request>,
+    },
+    /// Fork a save, and start playing the fork.
+    ForkAndPlay {
+        /// The `SaveId` of the save.
+        id: SaveId,
+        /// The `Request`s to handle once the fork is being played, e.g. to show the game. They
+        /// are dropped if forking or playing fails.
+        then: Vec<Request>,
+    },
+    /// Remove every branch of the tree in which every save was deleted (see `Playthrough::prune()`).
+    Prune,
+    /// Replace what the user wrote down about a save.
+    SetMetadata {
+        /// The `SaveId` of the save.
+        id: SaveId,
+        /// The new `SaveMetadata`.
+        metadata: SaveMetadata,
+    },
+    /// Stop playing the active save.
+    Stop,
+}
@@ -1,7 +1,11 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::{structs::ScreenId, traits::Screen};
+use crate::{
+    structs::{ScreenId, TransitionSettings},
+    traits::Screen,
+};
+use core::any::{Any, TypeId};
 
 /// A `Request` that a `ScreenManager` will complete.
 #[derive(Debug)]
@@ -22,6 +26,38 @@ pub enum ScreenManagerRequest {
         /// The `Screen` to add.
         screen: Box<dyn Screen>,
     },
+    /// Select the child `Screen` of the same type, if one, and add and select the `Screen`
+    /// otherwise.
+    AddOrFocusScreen {
+        /// The parent ID of the `Screen`.
+        parent_id: ScreenId,
+        /// The `Screen` to add.
+        screen: Box<dyn Screen>,
+    },
+    /// Pin the `Screen` with this `ScreenId`, so that it is never reclaimed.
+    Pin(ScreenId),
+    /// Unpin the `Screen` with this `ScreenId`.
+    Unpin(ScreenId),
+    /// Open an overlay over the current `Screen` (see `ScreenManager::open_overlay()`).
+    OpenOverlay {
+        /// The `Screen` to show in the overlay.
+        screen: Box<dyn Screen>,
+        /// Whether the screens and overlays below it can't be used while it is open.
+        modal: bool,
+    },
+    /// Close the top overlay, and hand this result (if any) to the `Screen` it was opened over
+    /// (see `ScreenManager::close_overlay()`).
+    CloseOverlay(Option<Box<dyn Any + Send + Sync>>),
+    /// Change how switching `Screen`s is animated.
+    SetTransitionSettings(TransitionSettings),
+    /// Show or hide the breadcrumb bar.
+    SetShowBreadcrumbs(bool),
     /// Go back to the previous `Screen`.
     Back,
+    /// Go back this many `Screen`s at once.
+    BackSteps(usize),
+    /// Go forward to the `Screen` that was last gone back from.
+    Forward,
+    /// Go back to the nearest `Screen` in the history whose type has this `TypeId`.
+    BackToScreenType(TypeId),
 }
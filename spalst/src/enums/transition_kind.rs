@@ -0,0 +1,30 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use clap::ValueEnum;
+
+/// How `ScreenManager` animates switching from one `Screen` to another (see `Transition`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum TransitionKind {
+    /// Switch instantly.
+    None,
+    /// Fade the new `Screen` in over the old one.
+    #[default]
+    Fade,
+    /// Slide the new `Screen` in and the old one out, sideways.
+    Slide,
+}
+
+impl TransitionKind {
+    /// Every `TransitionKind`.
+    pub const ALL: [Self; 3] = [Self::None, Self::Fade, Self::Slide];
+
+    /// Get the name of this `TransitionKind`, as shown to the user.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Fade => "Fade",
+            Self::Slide => "Slide",
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+/// A step of the `NewPlaythroughScreen` wizard, in the order they are shown in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WizardStep {
+    /// Naming the `Playthrough`.
+    #[default]
+    Name,
+    /// Choosing the `Difficulty` and the rules.
+    Rules,
+    /// Entering a world seed, if any.
+    Seed,
+    /// Looking over everything before creating the `Playthrough`.
+    Confirm,
+}
+
+impl WizardStep {
+    /// Every `WizardStep`, in the order they are shown in.
+    pub const ALL: [Self; 4] = [Self::Name, Self::Rules, Self::Seed, Self::Confirm];
+
+    /// Get the title of this `WizardStep`, as shown to the user.
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Rules => "Rules",
+            Self::Seed => "World seed",
+            Self::Confirm => "Confirm",
+        }
+    }
+
+    /// Get the step after this one, if any.
+    pub const fn next(self) -> Option<Self> {
+        match self {
+            Self::Name => Some(Self::Rules),
+            Self::Rules => Some(Self::Seed),
+            Self::Seed => Some(Self::Confirm),
+            Self::Confirm => None,
+        }
+    }
+
+    /// Get the step before this one, if any.
+    pub const fn previous(self) -> Option<Self> {
+        match self {
+            Self::Name => None,
+            Self::Rules => Some(Self::Name),
+            Self::Seed => Some(Self::Rules),
+            Self::Confirm => Some(Self::Seed),
+        }
+    }
+}
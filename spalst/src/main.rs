@@ -12,6 +12,7 @@ use crate::{
     structs::{App, AppState},
 };
 use color_eyre::Result;
+use core::time::Duration;
 use eframe::{CreationContext, NativeOptions};
 use egui::Context;
 use std::sync::{Arc, Mutex};
@@ -28,23 +29,56 @@ async fn main() -> Result<()> {
     // Initialize `color_eyre`
     color_eyre::install()?;
 
-    let (tx, rx): (UnboundedSender<Request>, UnboundedReceiver<Request>) = mpsc::unbounded_channel();
     let app_state: Arc<Mutex<AppState>> = Arc::new(Mutex::new(AppState::try_new().await?));
 
-    let app: App = App::new(Arc::clone(&app_state), tx);
+    let ran_command: bool = app_state.lock().unwrap().run_command()?;
+    if !ran_command {
+        run_window(&app_state)?;
+    }
+
+    #[cfg(feature = "logging")]
+    if let Some(logger) = app_state.lock().unwrap().logger.as_mut() {
+        logger.successful_exit = true;
+    }
+
+    Ok(())
+}
 
-    eframe::run_native(
+/// Open the window, and run it until it is closed.
+///
+/// # Errors
+/// If running the window fails.
+/// If handling a `Request` or rendering fails (see `AppState::take_handle_error()` and
+/// `AppState::take_render_error()`).
+/// If ending the session fails (see `AppState::end_session()`).
+#[cfg_attr(feature = "logging", instrument)]
+fn run_window(app_state: &Arc<Mutex<AppState>>) -> Result<()> {
+    let (tx, rx): (UnboundedSender<Request>, UnboundedReceiver<Request>) = mpsc::unbounded_channel();
+    let autosave_interval: Option<Duration> = app_state.lock().unwrap().autosave_interval();
+    let autosave_timer: Option<JoinHandle<()>> = autosave_interval.map(|interval: Duration| tokio::spawn(AppState::run_autosave_timer(interval, tx.clone())));
+    let app: App = App::new(Arc::clone(app_state), tx);
+
+    let run_result: eframe::Result = eframe::run_native(
         "Spalst",
         NativeOptions::default(),
         Box::new({
-            let app_state: Arc<Mutex<AppState>> = Arc::clone(&app_state);
+            let app_state: Arc<Mutex<AppState>> = Arc::clone(app_state);
             move |cc: &CreationContext<'_>| {
                 let ctx: Context = cc.egui_ctx.clone();
                 let _: JoinHandle<()> = tokio::spawn(AppState::process_requests(app_state, rx, ctx));
                 Ok(Box::new(app))
             }
         }),
-    )?;
+    );
+    // The timer holds a sender, so the channel would never close while it runs, and it could
+    // autosave in the middle of ending the session.
+    if let Some(timer) = autosave_timer {
+        timer.abort();
+    }
+    run_result?;
+
+    // Reported after the other errors, since those are probably the reason it failed.
+    let end_session_result: Result<()> = app_state.lock().unwrap().end_session();
 
     app_state
         .lock()
@@ -58,10 +92,7 @@ async fn main() -> Result<()> {
         .take_render_error()
         .map_or(Ok(()), Err)?;
 
-    #[cfg(feature = "logging")]
-    if let Some(logger) = app_state.lock().unwrap().logger.as_mut() {
-        logger.successful_exit = true;
-    }
+    end_session_result?;
 
     Ok(())
 }
@@ -6,3 +6,4 @@ mod paths;
 pub use paths::HOME_DIR;
 #[cfg(feature = "logging")]
 pub use paths::LOG_FILES_DIR;
+pub use paths::PLAYTHROUGHS_DIR;
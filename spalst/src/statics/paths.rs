@@ -20,3 +20,6 @@ pub static HOME_DIR: LazyLock<AbsolutePathBuf> = LazyLock::new(|| {
 /// The directory containing logfiles.
 #[cfg(feature = "logging")]
 pub static LOG_FILES_DIR: LazyLock<AbsolutePathBuf> = LazyLock::new(|| HOME_DIR.join(".local/state/spalst/logs"));
+
+/// The directory containing stored `Playthrough`s, one file per `Playthrough`.
+pub static PLAYTHROUGHS_DIR: LazyLock<AbsolutePathBuf> = LazyLock::new(|| HOME_DIR.join(".local/state/spalst/playthroughs"));
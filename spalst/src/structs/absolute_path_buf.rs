@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 use tracing::instrument;
 
 /// Same as a `PathBuf`, except this is guaranteed to be an absolute path.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AbsolutePathBuf(PathBuf);
 
 impl AbsolutePathBuf {
@@ -19,6 +19,10 @@ pub struct App {
     /// Will become `None` once a rendering error is encountered.
     /// That will drop the channel.
     pub(super) tx: Option<UnboundedSender<Request>>,
+
+    /// The title the window was last given (see `ScreenManager::window_title()`), so that it is
+    /// only changed when it has to be.
+    pub(super) window_title: String,
 }
 
 impl App {
@@ -30,6 +34,7 @@ impl App {
         Self {
             state,
             tx: Some(tx),
+            window_title: String::new(),
         }
     }
 }
@@ -1,10 +1,16 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::{enums::Request, error, structs::App};
+use crate::{
+    enums::Request,
+    error,
+    structs::{App, AppState, ScreenManager},
+};
 use color_eyre::{Result, eyre::OptionExt as _};
+use core::time::Duration;
 use eframe::Frame;
-use egui::{Ui, ViewportCommand};
+use egui::{InputState, Ui, ViewportCommand};
+use std::sync::MutexGuard;
 
 impl eframe::App for App {
     fn ui(
@@ -16,7 +22,26 @@ impl eframe::App for App {
         elegance::Theme::slate().install(ui.ctx());
 
         let result: Result<()> = || -> Result<()> {
-            let new_requests: Vec<Request> = self.state.lock().unwrap().screen_manager.render(ui, self)?;
+            let new_requests: Vec<Request> = {
+                let mut state: MutexGuard<'_, AppState> = self.state.lock().unwrap();
+                let mut new_requests: Vec<Request> = ScreenManager::render(ui, &mut state)?;
+                new_requests.extend(
+                    ui.input_mut(|input: &mut InputState| state.screen_manager.history_shortcut(input))
+                        .map(Request::ScreenManager),
+                );
+                let window_title: String = state.screen_manager.window_title();
+                if window_title != self.window_title {
+                    ui.ctx()
+                        .send_viewport_cmd(ViewportCommand::Title(window_title.clone()));
+                    self.window_title = window_title;
+                }
+                if state.update_playtime_clock(ui.input(|input: &InputState| input.focused)) {
+                    // Keeps the displayed playtime ticking.
+                    ui.ctx().request_repaint_after(Duration::from_secs(1));
+                }
+                drop(state);
+                new_requests
+            };
             for request in new_requests {
                 self.tx
                     .as_ref()
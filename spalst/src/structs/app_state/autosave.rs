@@ -0,0 +1,81 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::Request,
+    info,
+    structs::{AppState, Playthrough, SaveId},
+};
+use color_eyre::eyre::{OptionExt as _, Result};
+use core::time::Duration;
+use tokio::{
+    sync::mpsc::UnboundedSender,
+    time::{self, Instant, Interval, MissedTickBehavior},
+};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Get how long to wait between autosaves, if the autosave timer is turned on.
+    pub const fn autosave_interval(&self) -> Option<Duration> {
+        self.args.autosave_interval()
+    }
+
+    /// Autosave the active save, and write the open `Playthrough` to disk.
+    ///
+    /// The playtime of the active save is committed first (see `AppState::commit_playtime()`).
+    /// Nothing happens if no save is active, or if it wasn't played since it was last autosaved.
+    /// Otherwise, the fork made by the autosave becomes the active save (see
+    /// `Playthrough::autosave()`).
+    ///
+    /// # Errors
+    /// If committing the playtime fails.
+    /// If a save is active, but no `Playthrough` is open.
+    /// If autosaving or writing the `Playthrough` fails.
+    /// If remembering the fork fails (see `AppState::remember_active_save()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn autosave(&mut self) -> Result<()> {
+        let Some(active_save) = self.active_save else {
+            return Ok(());
+        };
+        self.commit_playtime()?;
+        let playthrough: &mut Playthrough = self
+            .open_playthrough
+            .as_mut()
+            .ok_or_eyre("A save is active while no playthrough is open.")?;
+        if playthrough.get_save(active_save)?.get_playtime().is_zero() {
+            return Ok(());
+        }
+
+        let fork: SaveId = playthrough.autosave(active_save, self.args.autosaves)?;
+        self.active_save = Some(fork);
+        self.playthrough_store.save(playthrough)?;
+        info!(
+            "Autosaved save {} of playthrough \"{}\" as save {}.",
+            *active_save,
+            playthrough.name(),
+            *fork
+        );
+        self.remember_active_save()
+    }
+
+    /// Send a `Request::Autosave` every `interval`, until the channel is closed.
+    ///
+    /// The first one is sent after a whole `interval`, not right away. If sending falls behind,
+    /// the missed autosaves are skipped instead of being sent all at once.
+    pub async fn run_autosave_timer(
+        interval: Duration,
+        tx: UnboundedSender<Request>,
+    ) {
+        let mut ticks: Interval = time::interval(interval);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // Without this, the first tick would complete right away.
+        ticks.reset();
+        loop {
+            let _: Instant = ticks.tick().await;
+            if tx.send(Request::Autosave).is_err() {
+                return;
+            }
+        }
+    }
+}
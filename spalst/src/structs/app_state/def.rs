@@ -3,9 +3,14 @@
 
 #[cfg(feature = "logging")]
 use crate::structs::Logger;
-use crate::structs::{ArgsParser, MainMenuScreen, ScreenId, ScreenManager};
+use crate::{
+    error,
+    statics::PLAYTHROUGHS_DIR,
+    structs::{ArgsParser, MainMenuScreen, Playthrough, PlaythroughRegistry, PlaythroughStore, PlaythroughSummary, PlaytimeClock, PruneReport, SaveId, ScreenId, ScreenManager, TransitionSettings},
+};
 use clap::Parser as _;
 use color_eyre::{Report, eyre::Result};
+use core::any::TypeId;
 
 /// The actual state of the app.
 ///
@@ -22,10 +27,41 @@ pub struct AppState {
     pub(super) render_error: Option<Report>,
 
     /// CLI arguments passed to the program.
-    args: ArgsParser,
+    pub(super) args: ArgsParser,
     /// The logger.
     #[cfg(feature = "logging")]
     pub logger: Option<Logger>,
+    /// Where `Playthrough`s are stored.
+    pub playthrough_store: PlaythroughStore,
+    /// Summaries of every stored `Playthrough`.
+    ///
+    /// Refreshed whenever a `PlaythroughRequest` is handled.
+    pub playthrough_summaries: Vec<PlaythroughSummary>,
+    /// Which save of every `Playthrough` was played last.
+    pub registry: PlaythroughRegistry,
+    /// The name of the `Playthrough` that "Continue" continues, if there is a save to continue
+    /// from (see `AppState::refresh_continue_target()`).
+    pub continue_target: Option<String>,
+    /// The result of the last export or import of a `Playthrough`, to show to the user.
+    pub archive_status: Option<String>,
+    /// Why opening or continuing the last `Playthrough` failed, if it did, to show to the user.
+    pub open_error: Option<String>,
+    /// Why handling the last `SaveRequest` failed, if it did, to show to the user.
+    pub save_error: Option<String>,
+    /// Why creating the last `Playthrough` failed, if it did, to show to the user.
+    pub create_error: Option<String>,
+    /// Why the last rename, duplicate or delete of a `Playthrough` failed, if it did, to show to
+    /// the user.
+    pub manage_error: Option<String>,
+    /// The `Playthrough` that is being looked at or played, if any.
+    pub open_playthrough: Option<Playthrough>,
+    /// The save of `open_playthrough` that is being played, if any.
+    pub active_save: Option<SaveId>,
+    /// What the last `SaveRequest::Prune` of `open_playthrough` removed, if it was pruned since it
+    /// was opened.
+    pub prune_report: Option<PruneReport>,
+    /// How long `active_save` has been played for, since its playtime was last committed.
+    pub playtime_clock: PlaytimeClock,
     /// The `Screen` manager.
     pub screen_manager: ScreenManager,
 }
@@ -36,6 +72,9 @@ impl AppState {
     /// # Errors
     /// If initializing the `Logger` fails (`Logger::try_init_new` function). Exclusive to the
     /// `logging` feature.
+    /// If creating the directory of the `PlaythroughStore` fails.
+    /// If the screen passed with `--screen` can't be opened, and going back to the main menu from
+    /// the screens opened on the way fails.
     #[cfg_attr(
         not(feature = "logging"),
         expect(clippy::unused_async, reason = "Only necessary to initialize Logger.")
@@ -51,16 +90,69 @@ impl AppState {
         let args: ArgsParser = ArgsParser::parse();
         #[cfg(feature = "logging")]
         let logger: Option<Logger> = args.log.then_some(Logger::try_init_new(&args).await?);
+        let playthrough_store: PlaythroughStore = PlaythroughStore::new(
+            PLAYTHROUGHS_DIR.clone(),
+            args.save_backups,
+            args.trash_retention(),
+        );
+        playthrough_store.ensure_dir()?;
+        // A broken registry only costs the "Continue" button, so it isn't worth refusing to start.
+        let registry: PlaythroughRegistry = playthrough_store
+            .load_registry()
+            .unwrap_or_else(|report: Report| {
+                error!("{report:#}");
+                PlaythroughRegistry::default()
+            });
         let mut screen_manager: ScreenManager = ScreenManager::new();
-        let _: ScreenId = screen_manager.add_select_screen(screen_manager.root_id(), Box::new(MainMenuScreen))?;
+        // The channel for `Request`s doesn't exist yet, but `MainMenuScreen` doesn't make any when it
+        // is entered anyway.
+        let main_menu: ScreenId = screen_manager.add_select_screen(
+            screen_manager.root_id(),
+            Box::new(MainMenuScreen),
+            &mut Vec::new(),
+        )?;
+        screen_manager.set_transition_settings(TransitionSettings::new(args.transition, args.reduce_motion));
+        screen_manager.set_show_breadcrumbs(args.breadcrumbs);
 
-        Ok(Self {
+        let mut state: Self = Self {
             handle_error: None,
             render_error: None,
             args,
             #[cfg(feature = "logging")]
             logger,
+            playthrough_store,
+            playthrough_summaries: Vec::new(),
+            registry,
+            continue_target: None,
+            archive_status: None,
+            open_error: None,
+            save_error: None,
+            create_error: None,
+            manage_error: None,
+            open_playthrough: None,
+            active_save: None,
+            prune_report: None,
+            playtime_clock: PlaytimeClock::default(),
             screen_manager,
-        })
+        };
+        if let Some(route) = state.args.screen.clone() {
+            // A route that can't be opened only costs the screens it leads to, so the app starts at
+            // the main menu instead.
+            if let Err(report) = state.open_route(&route) {
+                error!("Failed to open the route {route:?}, starting at the main menu instead: {report:#}");
+                if state.screen_manager.current_id() != main_menu {
+                    let _: ScreenId = state
+                        .screen_manager
+                        .back_to_screen_type(TypeId::of::<MainMenuScreen>(), &mut Vec::new())?;
+                }
+                state.open_playthrough = None;
+                state.active_save = None;
+                state.open_error = Some(format!("Failed to open the route: {report:#}"));
+            }
+            // Starting up into a route shouldn't animate through it.
+            state.screen_manager.skip_transition();
+        }
+        state.refresh_continue_target();
+        Ok(state)
     }
 }
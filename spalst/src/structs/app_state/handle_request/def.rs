@@ -15,8 +15,11 @@ impl AppState {
         request: Request,
     ) -> Result<()> {
         match request {
-            Request::Quit => todo!(),
+            // `AppState::process_requests()` closes the window once this is handled.
+            Request::Autosave | Request::Quit => state.lock().unwrap().autosave(),
             Request::ScreenManager(sm_request) => Self::handle_screen_manager_request(state, sm_request),
+            Request::Playthrough(playthrough_request) => Self::handle_playthrough_request(state, playthrough_request),
+            Request::Save(save_request) => Self::handle_save_request(state, save_request),
         }
     }
 }
@@ -2,4 +2,6 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 mod def;
+mod playthrough;
+mod save;
 mod screen_manager;
@@ -0,0 +1,204 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{PlaythroughRequest, Request},
+    error,
+    structs::{AppState, Playthrough, PlaythroughSettings, PlaythroughStore, PlaythroughSummary, SaveId, SaveMark},
+};
+use color_eyre::{Result, eyre::OptionExt as _};
+use std::sync::{Arc, Mutex, MutexGuard};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Handle a `PlaythroughRequest`.
+    ///
+    /// The `Request`s to handle once a `Playthrough` is created (see `PlaythroughRequest::Create`)
+    /// are handled right after it.
+    #[cfg_attr(feature = "logging", instrument(skip(state)))]
+    pub(super) fn handle_playthrough_request(
+        state: &Arc<Mutex<Self>>,
+        request: PlaythroughRequest,
+    ) -> Result<()> {
+        let mut app_state: MutexGuard<'_, Self> = state.lock().unwrap();
+        let mut follow_ups: Vec<Request> = Vec::new();
+
+        match request {
+            PlaythroughRequest::Refresh => {}
+            PlaythroughRequest::Open(name) => {
+                app_state.active_save = None;
+                app_state.prune_report = None;
+                app_state.save_error = None;
+                app_state.open_error = None;
+                app_state.open_playthrough = match app_state.playthrough_store.load(&name) {
+                    Ok(playthrough) => Some(playthrough),
+                    Err(report) => {
+                        error!("{report:#}");
+                        app_state.open_error = Some(format!("Failed to open \"{name}\": {report:#}"));
+                        None
+                    }
+                };
+            }
+            PlaythroughRequest::Create {
+                name,
+                settings,
+                then,
+            } => {
+                app_state.create_error = None;
+                match app_state.create_playthrough(&name, settings) {
+                    Ok(()) => follow_ups = then,
+                    Err(report) => {
+                        error!("{report:#}");
+                        app_state.create_error = Some(format!("Failed to create \"{name}\": {report:#}"));
+                    }
+                }
+            }
+            PlaythroughRequest::Continue(name) => {
+                app_state.open_error = None;
+                if let Err(report) = app_state.continue_playthrough(&name) {
+                    error!("{report:#}");
+                    app_state.active_save = None;
+                    app_state.open_playthrough = None;
+                    app_state.open_error = Some(format!("Failed to continue \"{name}\": {report:#}"));
+                }
+            }
+            PlaythroughRequest::Rename { name, new_name } => {
+                app_state.manage_error = None;
+                if let Err(report) = app_state.rename_playthrough(&name, &new_name) {
+                    error!("{report:#}");
+                    app_state.manage_error = Some(format!("Failed to rename \"{name}\": {report:#}"));
+                }
+            }
+            PlaythroughRequest::Duplicate { name, new_name } => {
+                app_state.manage_error = None;
+                if let Err(report) = app_state.playthrough_store.duplicate(&name, &new_name) {
+                    error!("{report:#}");
+                    app_state.manage_error = Some(format!("Failed to duplicate \"{name}\": {report:#}"));
+                }
+            }
+            PlaythroughRequest::Delete(name) => {
+                app_state.manage_error = None;
+                if let Err(report) = app_state.delete_playthrough(&name) {
+                    error!("{report:#}");
+                    app_state.manage_error = Some(format!("Failed to delete \"{name}\": {report:#}"));
+                }
+            }
+            PlaythroughRequest::Export { name, save, path } => {
+                app_state.archive_status = Some(
+                    match app_state.playthrough_store.export(&name, save, &path) {
+                        Ok(()) => format!("Exported \"{name}\" to {}.", path.display()),
+                        Err(report) => {
+                            error!("{report:#}");
+                            format!("Failed to export \"{name}\": {report:#}")
+                        }
+                    },
+                );
+            }
+            PlaythroughRequest::Import { path, name } => {
+                app_state.archive_status = Some(
+                    match app_state.playthrough_store.import(&path, name.as_deref()) {
+                        Ok(stored_name) => format!("Imported \"{stored_name}\" from {}.", path.display()),
+                        Err(report) => {
+                            error!("{report:#}");
+                            format!("Failed to import {}: {report:#}", path.display())
+                        }
+                    },
+                );
+            }
+        }
+
+        // Every stored `Playthrough` is loaded to summarize it, which shouldn't keep the screens
+        // from rendering meanwhile.
+        let store: PlaythroughStore = app_state.playthrough_store.clone();
+        drop(app_state);
+        let summaries: Vec<PlaythroughSummary> = store.summaries()?;
+
+        let mut guard: MutexGuard<'_, Self> = state.lock().unwrap();
+        guard.playthrough_summaries = summaries;
+        guard.refresh_continue_target();
+        drop(guard);
+
+        for follow_up in follow_ups {
+            Self::handle_request(state, follow_up)?;
+        }
+        Ok(())
+    }
+
+    /// Create a new `Playthrough` with this name and these rules, store it, and start playing its
+    /// root save.
+    ///
+    /// # Errors
+    /// If the name is invalid or taken (see `PlaythroughStore::ensure_free_name()`).
+    /// If making or playing the root save fails.
+    /// If writing the `Playthrough` or the `PlaythroughRegistry` fails.
+    fn create_playthrough(
+        &mut self,
+        name: &str,
+        settings: PlaythroughSettings,
+    ) -> Result<()> {
+        self.playthrough_store.ensure_free_name(name)?;
+        let mut playthrough: Playthrough = Playthrough::new(name.to_owned());
+        playthrough.set_settings(settings);
+        let root: SaveId = playthrough.make_root_node()?;
+        playthrough.play_save(root)?;
+        self.playthrough_store.save(&playthrough)?;
+        self.active_save = Some(root);
+        self.prune_report = None;
+        self.save_error = None;
+        self.open_playthrough = Some(playthrough);
+        self.remember_active_save()
+    }
+
+    /// Load the `Playthrough` with this name, and continue playing the save that was played last.
+    ///
+    /// # Errors
+    /// If loading or saving the `Playthrough` fails, or it was never played.
+    /// If resuming fails (see `Playthrough::resume()`).
+    /// If writing the `PlaythroughRegistry` fails.
+    fn continue_playthrough(
+        &mut self,
+        name: &str,
+    ) -> Result<()> {
+        let mut playthrough: Playthrough = self.playthrough_store.load(name)?;
+        let path: &[SaveMark] = self
+            .registry
+            .slot(name)
+            .ok_or_eyre(format!("Playthrough \"{name}\" was never played."))?;
+        self.active_save = Some(playthrough.resume(path)?);
+        self.prune_report = None;
+        self.save_error = None;
+        self.playthrough_store.save(&playthrough)?;
+        self.open_playthrough = Some(playthrough);
+        self.remember_active_save()
+    }
+
+    /// Rename the stored `Playthrough` with this name, and its entry in the `PlaythroughRegistry`.
+    ///
+    /// # Errors
+    /// If renaming the `Playthrough` fails (see `PlaythroughStore::rename()`).
+    /// If writing the `PlaythroughRegistry` fails.
+    fn rename_playthrough(
+        &mut self,
+        name: &str,
+        new_name: &str,
+    ) -> Result<()> {
+        self.playthrough_store.rename(name, new_name)?;
+        self.registry.rename(name, new_name);
+        self.playthrough_store.save_registry(&self.registry)
+    }
+
+    /// Delete the stored `Playthrough` with this name, and forget it in the `PlaythroughRegistry`.
+    ///
+    /// # Errors
+    /// If deleting the `Playthrough` fails (see `PlaythroughStore::delete()`).
+    /// If writing the `PlaythroughRegistry` fails.
+    fn delete_playthrough(
+        &mut self,
+        name: &str,
+    ) -> Result<()> {
+        self.playthrough_store.delete(name)?;
+        self.registry.forget(name);
+        self.playthrough_store.save_registry(&self.registry)
+    }
+}
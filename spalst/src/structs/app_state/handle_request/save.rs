@@ -0,0 +1,88 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Request, SaveRequest},
+    error,
+    structs::{AppState, Playthrough, SaveId},
+};
+use color_eyre::{Report, Result, eyre::OptionExt as _};
+use std::sync::{Arc, Mutex, MutexGuard};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Handle a `SaveRequest`.
+    ///
+    /// The playtime of the active save is committed first (see `AppState::commit_playtime()`).
+    ///
+    /// If it fails, the reason is reported in `AppState::save_error`. Otherwise, the `Request`s to
+    /// handle once a save is being played (see `SaveRequest::Play`) are handled right after it.
+    #[cfg_attr(feature = "logging", instrument(skip(state)))]
+    pub(super) fn handle_save_request(
+        state: &Arc<Mutex<Self>>,
+        request: SaveRequest,
+    ) -> Result<()> {
+        let mut guard: MutexGuard<'_, Self> = state.lock().unwrap();
+        guard.save_error = None;
+        let follow_ups: Vec<Request> = guard
+            .apply_save_request(request)
+            .unwrap_or_else(|report: Report| {
+                error!("{report:#}");
+                guard.save_error = Some(format!("Failed to update the saves: {report:#}"));
+                Vec::new()
+            });
+        drop(guard);
+
+        for follow_up in follow_ups {
+            Self::handle_request(state, follow_up)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a `SaveRequest` to the open `Playthrough`, and save it to disk. Returns the `Request`s
+    /// to handle afterwards.
+    ///
+    /// # Errors
+    /// If committing the playtime of the active save fails.
+    /// If no `Playthrough` is open.
+    /// If the request can't be applied to the save it is about.
+    /// If writing the `Playthrough` or the `PlaythroughRegistry` fails.
+    fn apply_save_request(
+        &mut self,
+        request: SaveRequest,
+    ) -> Result<Vec<Request>> {
+        // Whatever happens to the active save, its playtime so far is written along with it.
+        self.commit_playtime()?;
+        let playthrough: &mut Playthrough = self
+            .open_playthrough
+            .as_mut()
+            .ok_or_eyre("Received a `SaveRequest` while no playthrough is open.")?;
+
+        let mut follow_ups: Vec<Request> = Vec::new();
+        match request {
+            SaveRequest::Fork(id) => {
+                let _: SaveId = playthrough.fork_save(id)?;
+            }
+            SaveRequest::Delete(id) => playthrough.delete_save(id)?,
+            SaveRequest::Restore(id) => playthrough.restore_save(id)?,
+            SaveRequest::Play { id, then } => {
+                playthrough.play_save(id)?;
+                self.active_save = Some(id);
+                follow_ups = then;
+            }
+            SaveRequest::ForkAndPlay { id, then } => {
+                self.active_save = Some(playthrough.fork_and_play(id)?);
+                follow_ups = then;
+            }
+            SaveRequest::SetMetadata { id, metadata } => playthrough.set_metadata(id, metadata)?,
+            SaveRequest::Prune => self.prune_report = Some(playthrough.prune()),
+            SaveRequest::Stop => self.active_save = None,
+        }
+
+        self.playthrough_store.save(playthrough)?;
+        self.remember_active_save()?;
+        self.refresh_continue_target();
+        Ok(follow_ups)
+    }
+}
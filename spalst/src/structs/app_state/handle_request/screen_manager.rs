@@ -2,32 +2,71 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 use crate::{
-    enums::ScreenManagerRequest,
+    enums::{Request, ScreenManagerRequest},
     structs::{AppState, ScreenId, ScreenManager},
 };
 use color_eyre::Result;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard};
 #[cfg(feature = "logging")]
 use tracing::instrument;
 
 impl AppState {
     /// Handle a `ScreenManagerRequest`.
+    ///
+    /// The `Request`s made by the lifecycle hooks of the screens (see `Screen`) are handled right
+    /// after it.
+    ///
+    /// # Errors
+    /// If the request or one of the `Request`s made by the lifecycle hooks fails.
     #[cfg_attr(feature = "logging", instrument(skip(state)))]
     pub(super) fn handle_screen_manager_request(
         state: &Arc<Mutex<Self>>,
         request: ScreenManagerRequest,
     ) -> Result<()> {
-        let screen_manager: &mut ScreenManager = &mut state.lock().unwrap().screen_manager;
+        let mut requests: Vec<Request> = Vec::new();
+        let mut guard: MutexGuard<'_, Self> = state.lock().unwrap();
+        let screen_manager: &mut ScreenManager = &mut guard.screen_manager;
 
         match request {
             ScreenManagerRequest::AddScreen { parent_id, screen } => screen_manager
                 .add_screen(parent_id, screen)
                 .map(|_: ScreenId| ()),
-            ScreenManagerRequest::SelectScreen(screen_id) => screen_manager.select_screen(screen_id),
+            ScreenManagerRequest::SelectScreen(screen_id) => screen_manager.select_screen(screen_id, &mut requests),
             ScreenManagerRequest::AddAndSelectScreen { parent_id, screen } => screen_manager
-                .add_select_screen(parent_id, screen)
+                .add_select_screen(parent_id, screen, &mut requests)
                 .map(|_: ScreenId| ()),
-            ScreenManagerRequest::Back => screen_manager.back().map(|_: ScreenId| ()),
+            ScreenManagerRequest::AddOrFocusScreen { parent_id, screen } => screen_manager
+                .add_or_focus_screen(parent_id, screen, &mut requests)
+                .map(|_: ScreenId| ()),
+            ScreenManagerRequest::Pin(screen_id) => screen_manager.set_pinned(screen_id, true),
+            ScreenManagerRequest::Unpin(screen_id) => screen_manager.set_pinned(screen_id, false),
+            ScreenManagerRequest::OpenOverlay { screen, modal } => {
+                screen_manager.open_overlay(screen, modal, &mut requests);
+                Ok(())
+            }
+            ScreenManagerRequest::CloseOverlay(result) => screen_manager.close_overlay(result, &mut requests),
+            ScreenManagerRequest::SetTransitionSettings(settings) => {
+                screen_manager.set_transition_settings(settings);
+                Ok(())
+            }
+            ScreenManagerRequest::SetShowBreadcrumbs(show) => {
+                screen_manager.set_show_breadcrumbs(show);
+                Ok(())
+            }
+            ScreenManagerRequest::Back => screen_manager.back(&mut requests).map(|_: ScreenId| ()),
+            ScreenManagerRequest::BackSteps(steps) => screen_manager
+                .back_steps(steps, &mut requests)
+                .map(|_: ScreenId| ()),
+            ScreenManagerRequest::Forward => screen_manager.forward(&mut requests).map(|_: ScreenId| ()),
+            ScreenManagerRequest::BackToScreenType(type_id) => screen_manager
+                .back_to_screen_type(type_id, &mut requests)
+                .map(|_: ScreenId| ()),
+        }?;
+        drop(guard);
+
+        for hook_request in requests {
+            Self::handle_request(state, hook_request)?;
         }
+        Ok(())
     }
 }
@@ -1,9 +1,14 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
+mod autosave;
 mod def;
 mod error_methods;
 mod handle_request;
+mod playtime;
 mod process_requests;
+mod registry;
+mod route;
+mod run_command;
 
 pub use def::AppState;
@@ -0,0 +1,72 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::AppState;
+use color_eyre::eyre::{OptionExt as _, Result};
+use core::time::Duration;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Start or pause the `PlaytimeClock`, depending on whether the active save is being played
+    /// right now, which it isn't while a modal overlay is open. Returns whether the clock is
+    /// running.
+    ///
+    /// `focused` is whether the window has focus.
+    pub fn update_playtime_clock(
+        &mut self,
+        focused: bool,
+    ) -> bool {
+        let running: bool = focused
+            && self.active_save.is_some()
+            && self
+                .screen_manager
+                .get_current_screen_node()
+                .screen
+                .is_gameplay()
+            && !self.screen_manager.has_modal_overlay();
+        self.playtime_clock.set_running(running);
+        running
+    }
+
+    /// Move the time measured by the `PlaytimeClock` into the playtime of the active save.
+    ///
+    /// This doesn't write the open `Playthrough` to disk. If no save is active, the measured time
+    /// is thrown away.
+    ///
+    /// # Errors
+    /// If a save is active, but no `Playthrough` is open.
+    /// If adding the playtime to the active save fails (see `Playthrough::add_playtime()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn commit_playtime(&mut self) -> Result<()> {
+        let playtime: Duration = self.playtime_clock.take();
+        let Some(active_save) = self.active_save else {
+            return Ok(());
+        };
+        self.open_playthrough
+            .as_mut()
+            .ok_or_eyre("A save is active while no playthrough is open.")?
+            .add_playtime(active_save, playtime)
+    }
+
+    /// If a save is being played, commit its playtime and write the open `Playthrough` to disk.
+    ///
+    /// Called once the window is closed, so no playtime is lost.
+    ///
+    /// # Errors
+    /// If committing the playtime fails (see `AppState::commit_playtime()`).
+    /// If writing the `Playthrough` fails (see `PlaythroughStore::save()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn end_session(&mut self) -> Result<()> {
+        if self.active_save.is_none() {
+            return Ok(());
+        }
+        self.playtime_clock.set_running(false);
+        self.commit_playtime()?;
+        self.active_save = None;
+        if let Some(ref playthrough) = self.open_playthrough {
+            self.playthrough_store.save(playthrough)?;
+        }
+        Ok(())
+    }
+}
@@ -15,6 +15,9 @@ impl AppState {
     ///
     /// If the channel is closed from the other side, the handling of requests is immediately
     /// stopped.
+    ///
+    /// Once a `Request::Quit` is handled, the channel is closed and the display is shut off as
+    /// well.
     pub async fn process_requests(
         state: Arc<Mutex<Self>>,
         mut rx: UnboundedReceiver<Request>,
@@ -25,11 +28,19 @@ impl AppState {
                 return;
             }
 
+            let quit: bool = matches!(request, Request::Quit);
             let result: Result<()> = Self::handle_request(&Arc::clone(&state), request);
             if let Err(report) = result {
                 rx.close();
                 ctx.send_viewport_cmd(ViewportCommand::Close);
                 state.lock().unwrap().set_handle_error(report);
+            } else if quit {
+                rx.close();
+                ctx.send_viewport_cmd(ViewportCommand::Close);
+            } else {
+                // The request may have changed what is shown (or whether playtime is counted), so
+                // don't wait for the next input to render it.
+                ctx.request_repaint();
             }
         }
     }
@@ -0,0 +1,53 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::{AppState, Playthrough};
+use color_eyre::eyre::Result;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Remember the active save in the `PlaythroughRegistry`, and write it to disk if it changed.
+    /// Nothing happens if no save is active.
+    ///
+    /// # Errors
+    /// If the active save isn't in the open `Playthrough`.
+    /// If writing the `PlaythroughRegistry` fails (see `PlaythroughStore::save_registry()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn remember_active_save(&mut self) -> Result<()> {
+        let (Some(playthrough), Some(active_save)) = (self.open_playthrough.as_ref(), self.active_save) else {
+            return Ok(());
+        };
+        if self
+            .registry
+            .record(playthrough.name(), playthrough.index_path(active_save)?)
+        {
+            self.playthrough_store.save_registry(&self.registry)?;
+        }
+        Ok(())
+    }
+
+    /// Check whether the `Playthrough` that was played last still has a save to continue from, and
+    /// remember its name in `AppState::continue_target` if so.
+    ///
+    /// The open `Playthrough` is used if it is that one. Otherwise, it is loaded from disk.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn refresh_continue_target(&mut self) {
+        let Some((name, path)) = self
+            .registry
+            .last_played()
+            .and_then(|name: &str| Some((name, self.registry.slot(name)?)))
+        else {
+            self.continue_target = None;
+            return;
+        };
+        let continuable: bool = match self.open_playthrough {
+            Some(ref playthrough) if playthrough.name() == name => playthrough.resume_point(path).is_some(),
+            Some(_) | None => self
+                .playthrough_store
+                .load(name)
+                .is_ok_and(|playthrough: Playthrough| playthrough.resume_point(path).is_some()),
+        };
+        self.continue_target = continuable.then(|| name.to_owned());
+    }
+}
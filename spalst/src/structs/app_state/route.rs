@@ -0,0 +1,86 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Request, Route},
+    structs::{AppState, GameScreen, ManagePlaythroughsScreen, NewPlaythroughScreen, Playthrough, SaveId, SaveTreeScreen, ScreenId, SettingsScreen},
+    traits::Screen,
+};
+use color_eyre::eyre::{Result, WrapErr as _};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Open the screens that lead to this `Route` from the current one (the main menu), the way
+    /// the user would click through them.
+    ///
+    /// Requests can't be sent before the channel for them exists, so whatever the lifecycle hooks
+    /// of the screens make (see `Screen`) is dropped. What the requests the user would cause do
+    /// is done right away instead.
+    ///
+    /// # Errors
+    /// If the `Route` leads to a `Playthrough` that can't be loaded, or to a save it doesn't have.
+    /// If playing the save, or writing the `Playthrough` or the `PlaythroughRegistry`, fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn open_route(
+        &mut self,
+        route: &Route,
+    ) -> Result<()> {
+        let main_menu: ScreenId = self.screen_manager.current_id();
+        match *route {
+            Route::MainMenu => {}
+            Route::Settings => {
+                let _: ScreenId = self.open_screen(main_menu, Box::new(SettingsScreen::new()))?;
+            }
+            Route::NewPlaythrough => {
+                self.playthrough_summaries = self.playthrough_store.summaries()?;
+                let _: ScreenId = self.open_screen(main_menu, Box::new(NewPlaythroughScreen::new()))?;
+            }
+            Route::ManagePlaythroughs => {
+                self.playthrough_summaries = self.playthrough_store.summaries()?;
+                let _: ScreenId = self.open_screen(main_menu, Box::new(ManagePlaythroughsScreen::new()))?;
+            }
+            Route::Playthrough { ref name, save } => {
+                self.playthrough_summaries = self.playthrough_store.summaries()?;
+                let mut playthrough: Playthrough = self
+                    .playthrough_store
+                    .load(name)
+                    .wrap_err_with(|| format!("Failed to open the playthrough of the route \"{name}\""))?;
+                let manage: ScreenId = self.open_screen(main_menu, Box::new(ManagePlaythroughsScreen::new()))?;
+                let tree: ScreenId = self.open_screen(manage, Box::new(SaveTreeScreen::new()))?;
+                if let Some(index) = save {
+                    let id: SaveId = playthrough.save_id_at(index)?;
+                    // Like continuing, a sealed save is played through a fork of it.
+                    let played: SaveId = if playthrough.is_sealed(id)? {
+                        playthrough.fork_and_play(id)?
+                    } else {
+                        playthrough.play_save(id)?;
+                        id
+                    };
+                    self.playthrough_store.save(&playthrough)?;
+                    self.open_playthrough = Some(playthrough);
+                    self.active_save = Some(played);
+                    self.remember_active_save()?;
+                    let _: ScreenId = self.open_screen(tree, Box::new(GameScreen))?;
+                } else {
+                    self.open_playthrough = Some(playthrough);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add this screen as a child of the screen the passed `ScreenId` points to, and select it.
+    ///
+    /// # Errors
+    /// If the call to `ScreenManager::add_select_screen()` fails.
+    fn open_screen(
+        &mut self,
+        parent_id: ScreenId,
+        screen: Box<dyn Screen>,
+    ) -> Result<ScreenId> {
+        let mut requests: Vec<Request> = Vec::new();
+        self.screen_manager
+            .add_select_screen(parent_id, screen, &mut requests)
+    }
+}
@@ -0,0 +1,139 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::Command,
+    structs::{AbsolutePathBuf, AppState, IntegrityReport, MigrationReport, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+use std::path::{self, PathBuf};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl AppState {
+    /// Run the `Command` given on the command line, if any. Returns whether one was run, in which
+    /// case the window shouldn't be opened.
+    ///
+    /// # Errors
+    /// If running the `Command` fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    #[expect(
+        clippy::print_stdout,
+        reason = "Commands are run from a terminal, so that's where their result is reported."
+    )]
+    pub fn run_command(&mut self) -> Result<bool> {
+        let Some(command) = self.args.command.take() else {
+            return Ok(false);
+        };
+
+        match command {
+            Command::Export { name, output, save } => {
+                self.playthrough_store.export(&name, save, &output)?;
+                println!("Exported playthrough \"{name}\" to {}.", output.display());
+            }
+            Command::Import { archive, name } => {
+                let name: String = self.playthrough_store.import(&archive, name.as_deref())?;
+                println!(
+                    "Imported playthrough \"{name}\" from {}.",
+                    archive.display()
+                );
+            }
+            Command::Migrate { name, dry_run } => {
+                let names: Vec<String> = match name {
+                    Some(only) => vec![only],
+                    None => self.playthrough_store.names()?,
+                };
+                for playthrough_name in names {
+                    let report: MigrationReport = self.playthrough_store.migrate(&playthrough_name, dry_run)?;
+                    if !report.is_needed() {
+                        println!("Playthrough \"{playthrough_name}\" is up to date.");
+                        continue;
+                    }
+                    println!(
+                        "Playthrough \"{playthrough_name}\" {} from format version {} to {}:",
+                        if dry_run {
+                            "would be upgraded"
+                        } else {
+                            "was upgraded"
+                        },
+                        report.from,
+                        report.to
+                    );
+                    for description in report.applied {
+                        println!("  {description}");
+                    }
+                    for change in report.changes {
+                        println!("    {change}");
+                    }
+                }
+            }
+            Command::Verify { dir, repair } => {
+                let other_store: Option<PlaythroughStore> = dir
+                    .map(|dir: PathBuf| -> Result<PlaythroughStore> {
+                        Ok(PlaythroughStore::new(
+                            AbsolutePathBuf::try_new(path::absolute(dir)?)?,
+                            self.args.save_backups,
+                            self.args.trash_retention(),
+                        ))
+                    })
+                    .transpose()?;
+                Self::verify_store(
+                    other_store.as_ref().unwrap_or(&self.playthrough_store),
+                    repair,
+                )?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// Check every `Playthrough` in this `PlaythroughStore` for structural problems, repairing them
+    /// if `repair` is set, and print what was found.
+    ///
+    /// A `Playthrough` that can't be checked at all doesn't keep the others from being checked.
+    ///
+    /// # Errors
+    /// If listing the stored `Playthrough`s fails.
+    #[expect(
+        clippy::print_stdout,
+        reason = "Commands are run from a terminal, so that's where their result is reported."
+    )]
+    fn verify_store(
+        store: &PlaythroughStore,
+        repair: bool,
+    ) -> Result<()> {
+        for name in store.names()? {
+            let result: Result<(IntegrityReport, IntegrityReport)> = if repair {
+                store.repair(&name)
+            } else {
+                store
+                    .verify(&name)
+                    .map(|report: IntegrityReport| (report.clone(), report))
+            };
+            let (found, left): (IntegrityReport, IntegrityReport) = match result {
+                Ok(reports) => reports,
+                Err(report) => {
+                    println!("Playthrough \"{name}\" can't be checked: {report:#}");
+                    continue;
+                }
+            };
+            if found.is_clean() {
+                println!("Playthrough \"{name}\" has no problems.");
+                continue;
+            }
+            println!(
+                "Playthrough \"{name}\" has {} problems:",
+                found.problems.len()
+            );
+            for problem in &found.problems {
+                println!("  {problem}");
+            }
+            if repair {
+                println!(
+                    "  {} of them are left after repairing it.",
+                    left.problems.len()
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,10 +3,19 @@
 
 #[cfg(feature = "logging")]
 use crate::enums::LogLevel;
+use crate::enums::{Command, Route, TransitionKind};
 use clap::{ArgAction, Parser};
+use core::time::Duration;
 
 /// Parse command-line arguments.
 #[derive(Debug, Parser)]
+#[cfg_attr(
+    feature = "logging",
+    expect(
+        clippy::struct_excessive_bools,
+        reason = "Every flag is its own command-line argument."
+    )
+)]
 #[command(name = "spalst")]
 #[command(about = "GUI game.")] // todo
 pub struct ArgsParser {
@@ -50,6 +59,88 @@ pub struct ArgsParser {
     )]
     #[cfg(feature = "logging")]
     pub rm_log: bool,
+    /// How many backups to keep of every stored `Playthrough`.
+    #[arg(
+        long,
+        default_value_t = 3,
+        help = "How many previous versions of every playthrough file to keep as backups."
+    )]
+    pub save_backups: usize,
+    /// How many days deleted saves can be restored for.
+    #[arg(
+        long,
+        default_value_t = 30,
+        help = "How many days a deleted save can be restored for, before its game data is thrown away."
+    )]
+    pub trash_retention_days: u64,
+    /// How many minutes to wait between autosaves. 0 turns the autosave timer off.
+    #[arg(
+        long,
+        default_value_t = 5,
+        help = "How many minutes to wait between autosaves while playing. 0 turns autosaving on a timer off."
+    )]
+    pub autosave_minutes: u64,
+    /// How many autosaves to keep in a row, before the oldest one is merged into the next.
+    #[arg(
+        long,
+        default_value_t = 3,
+        help = "How many autosaves in a row to keep, before the oldest one is merged into the next."
+    )]
+    pub autosaves: usize,
+    /// How switching screens is animated.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = TransitionKind::default(),
+        help = "How switching screens is animated."
+    )]
+    pub transition: TransitionKind,
+    /// Whether to turn animations off.
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "Turn animated transitions between screens off, whatever --transition is."
+    )]
+    pub reduce_motion: bool,
+    /// Whether to show the breadcrumb bar.
+    #[arg(
+        long,
+        default_value_t = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Show the path to the current screen above it, where every step can be clicked to go back to it."
+    )]
+    pub breadcrumbs: bool,
+    /// The screen to open on startup, if not the main menu.
+    #[arg(
+        long,
+        value_name = "ROUTE",
+        help = "Open this screen on startup instead of the main menu, like \"settings\" or \"playthrough:<name>/save:<index>\"."
+    )]
+    pub screen: Option<Route>,
+    /// What to do instead of opening the window, if anything.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl ArgsParser {
+    /// How long deleted saves can be restored for, as set by `trash_retention_days`.
+    pub const fn trash_retention(&self) -> Duration {
+        /// The number of seconds in a day.
+        const SECONDS_PER_DAY: u64 = 86_400;
+        Duration::from_secs(self.trash_retention_days.saturating_mul(SECONDS_PER_DAY))
+    }
+
+    /// How long to wait between autosaves, as set by `autosave_minutes`. `None` if the autosave
+    /// timer is turned off.
+    pub const fn autosave_interval(&self) -> Option<Duration> {
+        if self.autosave_minutes == 0 {
+            None
+        } else {
+            Some(Duration::from_mins(self.autosave_minutes))
+        }
+    }
 }
 
 /// The default max logging level.
@@ -1,18 +1,19 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::structs::Save;
+use crate::structs::{Save, SaveMetadata};
 use chrono::{DateTime, Utc};
 use core::time::Duration;
+use serde::{Deserialize, Serialize};
 
 /// A deleted save.
 ///
-/// Contains only metadata.
+/// Contains only metadata, including the `SaveMetadata` the user wrote down about it.
 ///
 /// Deleting saves is troublesome. Its metadata is lost, and its children will then point to the
 /// save's parent. Both of those aren't good.
 /// That is why this exists. It preserves its status as a `Node` and it also saves metadata.
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeletedSave {
     /// The playtime on this `Save`, before it was deleted. Does not include the playtime of its parent.
     playtime: Duration,
@@ -20,6 +21,31 @@ pub struct DeletedSave {
     /// The time when this `Save` was created from its parent `Save`.
     /// If it has no parent, this is the time when the `Playthrough` was created.
     birth_time: DateTime<Utc>,
+
+    /// What the user wrote down about this `Save`, before it was deleted.
+    metadata: SaveMetadata,
+}
+
+impl DeletedSave {
+    /// Get the playtime this `Save` had before it was deleted.
+    pub const fn get_playtime(&self) -> Duration {
+        self.playtime
+    }
+
+    /// Get the birth time of this `Save`.
+    pub const fn get_birth_time(&self) -> DateTime<Utc> {
+        self.birth_time
+    }
+
+    /// Get what the user wrote down about this `Save`.
+    pub const fn metadata(&self) -> &SaveMetadata {
+        &self.metadata
+    }
+
+    /// Get what the user wrote down about this `Save`, mutably.
+    pub const fn metadata_mut(&mut self) -> &mut SaveMetadata {
+        &mut self.metadata
+    }
 }
 
 impl From<&Save> for DeletedSave {
@@ -27,6 +53,7 @@ impl From<&Save> for DeletedSave {
         Self {
             playtime: save.get_playtime(),
             birth_time: save.get_birth_time(),
+            metadata: save.metadata().clone(),
         }
     }
 }
@@ -0,0 +1,29 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use core::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+/// Displays a `Duration` as hours, minutes and seconds (for example, `12h 05m 09s`).
+///
+/// `Duration` only implements `Debug`, which isn't meant for users.
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayDuration(pub Duration);
+
+impl Display for DisplayDuration {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let seconds: u64 = self.0.as_secs();
+        write!(
+            f,
+            "{}h {:02}m {:02}s",
+            seconds.div_euclid(3600),
+            seconds.rem_euclid(3600).div_euclid(60),
+            seconds.rem_euclid(60)
+        )
+    }
+}
@@ -0,0 +1,22 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use chrono::{DateTime, Local, Utc};
+use core::fmt::{self, Display, Formatter};
+
+/// Displays a `DateTime<Utc>` in local time, down to the minute (for example, `2025-01-31 14:05`).
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayTime(pub DateTime<Utc>);
+
+impl Display for DisplayTime {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        )
+    }
+}
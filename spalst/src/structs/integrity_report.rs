@@ -0,0 +1,18 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::enums::IntegrityProblem;
+
+/// What `Playthrough::verify()` found.
+#[derive(Clone, Debug, Default)]
+pub struct IntegrityReport {
+    /// Every problem that was found, in the order they were found in.
+    pub problems: Vec<IntegrityProblem>,
+}
+
+impl IntegrityReport {
+    /// Check whether no problems were found.
+    pub const fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
@@ -0,0 +1,19 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use color_eyre::eyre::Result;
+use serde_json::Value;
+
+/// One step of upgrading a stored `Playthrough` to the current `Playthrough::FORMAT_VERSION`.
+///
+/// Steps work on the JSON body of the file, before it is deserialized, so they can handle fields
+/// that `StoredPlaythrough` no longer has (or doesn't have yet). See `Playthrough::MIGRATIONS`.
+#[derive(Clone, Copy, Debug)]
+pub struct Migration {
+    /// The format version this step upgrades from. It upgrades to the next one.
+    pub from: u32,
+    /// What this step does, shown to the user.
+    pub description: &'static str,
+    /// Upgrade the body in place. Returns a description of every change it made.
+    pub upgrade: fn(&mut Value) -> Result<Vec<String>>,
+}
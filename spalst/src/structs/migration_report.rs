@@ -0,0 +1,22 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+/// What upgrading a stored `Playthrough` to the current format changed (see `Migration`).
+#[derive(Clone, Debug)]
+pub struct MigrationReport {
+    /// The format version the `Playthrough` was stored with.
+    pub from: u32,
+    /// The format version it was upgraded to.
+    pub to: u32,
+    /// The description of every `Migration` that was applied, in order.
+    pub applied: Vec<&'static str>,
+    /// What those `Migration`s changed, in order.
+    pub changes: Vec<String>,
+}
+
+impl MigrationReport {
+    /// Check whether the `Playthrough` had to be upgraded at all.
+    pub const fn is_needed(&self) -> bool {
+        self.from != self.to
+    }
+}
@@ -6,27 +6,73 @@ mod app;
 mod app_state;
 mod args_parser;
 mod deleted_save;
+mod display_duration;
+mod display_time;
+mod integrity_report;
 #[cfg(feature = "logging")]
 mod logger;
+mod migration;
+mod migration_report;
+mod overlay;
 mod playthrough;
+mod playthrough_draft;
+mod playthrough_registry;
+mod playthrough_settings;
+mod playthrough_store;
+mod playthrough_summary;
+mod playtime_clock;
+mod prune_report;
 mod save;
+mod save_diff;
+mod save_filter;
 mod save_id;
+mod save_mark;
+mod save_metadata;
+mod save_tree_layout;
 mod screen_id;
 mod screen_manager;
 mod screen_node;
 mod screens;
+mod stored_playthrough;
+mod stored_save;
+mod transition;
+mod transition_settings;
+mod trashed_save;
 
 pub use absolute_path_buf::AbsolutePathBuf;
 pub use app::App;
 pub use app_state::AppState;
 pub use args_parser::ArgsParser;
 pub use deleted_save::DeletedSave;
+pub use display_duration::DisplayDuration;
+pub use display_time::DisplayTime;
+pub use integrity_report::IntegrityReport;
 #[cfg(feature = "logging")]
 pub use logger::Logger;
+pub use migration::Migration;
+pub use migration_report::MigrationReport;
+pub use overlay::Overlay;
 pub use playthrough::Playthrough;
+pub use playthrough_draft::PlaythroughDraft;
+pub use playthrough_registry::PlaythroughRegistry;
+pub use playthrough_settings::PlaythroughSettings;
+pub use playthrough_store::PlaythroughStore;
+pub use playthrough_summary::PlaythroughSummary;
+pub use playtime_clock::PlaytimeClock;
+pub use prune_report::PruneReport;
 pub use save::Save;
+pub use save_diff::SaveDiff;
+pub use save_filter::SaveFilter;
 pub use save_id::SaveId;
+pub use save_mark::SaveMark;
+pub use save_metadata::SaveMetadata;
+pub use save_tree_layout::SaveTreeLayout;
 pub use screen_id::ScreenId;
 pub use screen_manager::ScreenManager;
 pub use screen_node::ScreenNode;
-pub use screens::{EmptyScreen, MainMenuScreen, SettingsScreen};
+pub use screens::{ConfirmDialog, EmptyScreen, GameScreen, MainMenuScreen, ManagePlaythroughsScreen, NewPlaythroughScreen, PauseScreen, SaveDiffScreen, SaveTreeScreen, SettingsScreen};
+pub use stored_playthrough::StoredPlaythrough;
+pub use stored_save::StoredSave;
+pub use transition::Transition;
+pub use transition_settings::TransitionSettings;
+pub use trashed_save::TrashedSave;
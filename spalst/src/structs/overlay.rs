@@ -0,0 +1,55 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::Request,
+    structs::{AppState, ScreenId},
+    traits::Screen,
+};
+use derive_new::new;
+use egui::{Align2, Area, Frame, Id, InnerResponse, Modal, ModalResponse, Order, Ui, Vec2};
+
+/// A `Screen` shown over the current one in `ScreenManager`, like a dialog or a popup.
+#[derive(Debug, new)]
+pub struct Overlay {
+    /// The value of the overlay.
+    pub screen: Box<dyn Screen>,
+    /// Whether the screens and overlays below this one can't be used while it is open.
+    pub modal: bool,
+    /// The `Screen` the overlay was opened over, which gets the result it is closed with.
+    pub owner_id: ScreenId,
+}
+
+impl Overlay {
+    /// Render the overlay at this index of the overlay stack by calling `ui()` (provided by
+    /// `Screen`) on its screen, centered over everything else.
+    ///
+    /// If it isn't `enabled`, its widgets can't be used.
+    pub fn show(
+        &mut self,
+        ui: &Ui,
+        state: &AppState,
+        index: usize,
+        enabled: bool,
+    ) -> Vec<Request> {
+        let id: Id = Id::new(("overlay", index));
+        let contents = |contents_ui: &mut Ui| -> Vec<Request> {
+            if !enabled {
+                contents_ui.disable();
+            }
+            self.screen.ui(contents_ui, state)
+        };
+        if self.modal {
+            let response: ModalResponse<Vec<Request>> = Modal::new(id).show(ui.ctx(), contents);
+            response.inner
+        } else {
+            let response: InnerResponse<InnerResponse<Vec<Request>>> = Area::new(id)
+                .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+                .order(Order::Foreground)
+                .show(ui.ctx(), |ui: &mut Ui| {
+                    Frame::popup(ui.style()).show(ui, contents)
+                });
+            response.inner.inner
+        }
+    }
+}
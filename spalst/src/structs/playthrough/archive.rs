@@ -0,0 +1,134 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    structs::{MigrationReport, Playthrough, SaveId, StoredPlaythrough, StoredSave},
+};
+use color_eyre::eyre::{OptionExt as _, Result};
+use indextree::NodeId;
+use std::collections::HashMap;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// The first word of every archive.
+    const ARCHIVE_MAGIC: &str = "spalst-archive";
+
+    /// Serialize this `Playthrough` into a self-contained archive, to be imported elsewhere with
+    /// `Playthrough::from_archive()`.
+    ///
+    /// If `branch` is given, only the saves on the path from the root save to that save are
+    /// archived.
+    ///
+    /// The format is the same as the one of `Playthrough::to_bytes()`, except that the header
+    /// starts with `spalst-archive` instead.
+    ///
+    /// # Errors
+    /// If `branch` doesn't point to a save.
+    /// If computing the checksum of a save or serializing to JSON fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn to_archive(
+        &self,
+        branch: Option<SaveId>,
+    ) -> Result<Vec<u8>> {
+        let stored: StoredPlaythrough = match branch {
+            Some(id) => self.branch_to_stored(id)?,
+            None => self.to_stored()?,
+        };
+        Self::with_header(Self::ARCHIVE_MAGIC, &stored)
+    }
+
+    /// Deserialize a `Playthrough` from an archive made with `Playthrough::to_archive()`.
+    ///
+    /// The saves get fresh `SaveId`s, numbered from 1 in the order they were archived in. Archives
+    /// made with an older `FORMAT_VERSION` are upgraded first, like stored `Playthrough`s.
+    ///
+    /// # Errors
+    /// If the archive was made with a newer `FORMAT_VERSION`, or is malformed in any other way
+    /// (see `Playthrough::from_bytes_migrated()`).
+    #[cfg_attr(feature = "logging", instrument(skip(bytes)))]
+    pub fn from_archive(bytes: &[u8]) -> Result<Self> {
+        let (stored, _): (StoredPlaythrough, MigrationReport) = Self::without_header(bytes, Self::ARCHIVE_MAGIC)?;
+        Self::from_stored(Self::renumbered(stored)?)
+    }
+
+    /// Get the `SaveId` of the save with this index, as shown to the user.
+    ///
+    /// # Errors
+    /// If no save has this index.
+    pub fn save_id_at(
+        &self,
+        index: usize,
+    ) -> Result<SaveId> {
+        self.arena
+            .iter_node_ids()
+            .find(|id: &NodeId| usize::from(*id) == index)
+            .map(SaveId::from)
+            .filter(|id: &SaveId| self.get_save(*id).is_ok())
+            .ok_or_eyre(format!(
+                "Playthrough \"{}\" has no save {index}.",
+                self.name
+            ))
+    }
+
+    /// Flatten the path from the root save to the save this `SaveId` points to into a
+    /// `StoredPlaythrough`.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    /// If computing the checksum of a save fails.
+    fn branch_to_stored(
+        &self,
+        id: SaveId,
+    ) -> Result<StoredPlaythrough> {
+        let path: Vec<SaveId> = self.path_from_root(id)?;
+        let mut saves: Vec<StoredSave> = Vec::with_capacity(path.len());
+        let mut parent: Option<usize> = None;
+        for save_id in &path {
+            let index: usize = (**save_id).into();
+            saves.push(StoredSave::try_new(
+                index,
+                parent,
+                self.get_save(*save_id)?.clone(),
+            )?);
+            parent = Some(index);
+        }
+
+        Ok(StoredPlaythrough {
+            name: self.name.clone(),
+            settings: self.settings,
+            last_played: self.last_played,
+            root_save: path.first().map(|root: &SaveId| (**root).into()),
+            saves,
+        })
+    }
+
+    /// Give every save of a `StoredPlaythrough` a new index, from 1 upwards in the order they are
+    /// stored in.
+    ///
+    /// # Errors
+    /// If two stored saves have the same index.
+    /// If a parent or the root save points to an index that wasn't stored.
+    fn renumbered(mut stored: StoredPlaythrough) -> Result<StoredPlaythrough> {
+        let mut new_indexes: HashMap<usize, usize> = HashMap::with_capacity(stored.saves.len());
+        for (new_index, save) in (1..).zip(&stored.saves) {
+            if new_indexes.insert(save.id, new_index).is_some() {
+                bail_log!("Multiple archived saves have the index {}.", save.id);
+            }
+        }
+        let new_index = |index: usize| -> Result<usize> {
+            new_indexes
+                .get(&index)
+                .copied()
+                .ok_or_eyre(format!("Archive points to the missing save {index}."))
+        };
+
+        for save in &mut stored.saves {
+            save.id = new_index(save.id)?;
+            save.parent = save.parent.map(new_index).transpose()?;
+        }
+        stored.root_save = stored.root_save.map(new_index).transpose()?;
+        Ok(stored)
+    }
+}
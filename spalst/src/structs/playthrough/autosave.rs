@@ -0,0 +1,102 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    enums::SaveEntry,
+    structs::{Playthrough, SaveId},
+};
+use color_eyre::eyre::Result;
+use core::time::Duration;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Autosave the save this `SaveId` points to, which is being played. Returns the `SaveId` of
+    /// the save to keep playing.
+    ///
+    /// The save is forked and the fork is played instead (see `Playthrough::fork_and_play()`), so
+    /// the sealed save keeps the game as it was at the time of the autosave. The fork is marked as
+    /// made by an autosave.
+    ///
+    /// Only the newest `keep` autosaves directly above the fork are kept. Older ones are merged into
+    /// their only child (see `Playthrough::merge_into_child()`), so a long session doesn't grow the
+    /// tree by one save per autosave. Autosaves that were pinned, or forked by the user as well,
+    /// are always kept and don't count.
+    ///
+    /// # Errors
+    /// If forking fails (see `Playthrough::fork_and_play()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn autosave(
+        &mut self,
+        id: SaveId,
+        keep: usize,
+    ) -> Result<SaveId> {
+        let fork: SaveId = self.fork_and_play(id)?;
+        if let SaveEntry::Active(ref mut save) = *self.get_save_mut(fork)? {
+            save.mark_autosave();
+        }
+
+        let autosaves: Vec<SaveId> = self
+            .ancestors(fork)?
+            .into_iter()
+            .take_while(|ancestor: &SaveId| {
+                self.get_save(*ancestor)
+                    .is_ok_and(|entry: &SaveEntry| matches!(*entry, SaveEntry::Active(ref save) if save.is_autosave()))
+            })
+            .filter(|autosave: &SaveId| self.is_mergeable(*autosave))
+            .collect();
+        for old_autosave in autosaves.into_iter().skip(keep) {
+            self.merge_into_child(old_autosave)?;
+        }
+        Ok(fork)
+    }
+
+    /// Check whether the save this `SaveId` points to is an autosave that can be merged into its
+    /// child: it isn't deleted or pinned, and its only child isn't deleted.
+    fn is_mergeable(
+        &self,
+        id: SaveId,
+    ) -> bool {
+        let Ok(entry) = self.get_save(id) else {
+            return false;
+        };
+        let SaveEntry::Active(ref save) = *entry else {
+            return false;
+        };
+        save.is_autosave()
+            && !save.metadata().pinned
+            && matches!(
+                *self.children(id).unwrap_or_default(),
+                [child] if self
+                    .get_save(child)
+                    .is_ok_and(|child_entry: &SaveEntry| matches!(*child_entry, SaveEntry::Active(_)))
+            )
+    }
+
+    /// Remove the save this `SaveId` points to, and move its playtime to its only child, which
+    /// takes its place in the tree. The playtime of the child including its ancestors stays the
+    /// same.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save that can be merged (see
+    /// `Playthrough::is_mergeable()`).
+    fn merge_into_child(
+        &mut self,
+        id: SaveId,
+    ) -> Result<()> {
+        if !self.is_mergeable(id) {
+            bail_log!(
+                "Passed ID (with value {}) points to a save that can't be merged into its child.",
+                *id
+            );
+        }
+        let playtime: Duration = self.get_save(id)?.get_playtime();
+        for child in self.children(id)? {
+            self.add_playtime(child, playtime)?;
+        }
+        // The children of a removed node take its place.
+        id.remove(&mut self.arena);
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    enums::SaveEntry,
+    structs::{PlaythroughSettings, SaveId, TrashedSave, save::Save},
+};
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::{OptionExt as _, Result};
+use core::time::Duration;
+use derive_new::new;
+use indextree::{Arena, Node};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+/// A game `Playthrough`.
+///
+/// Once a save is forked, it is sealed: the user is forbidden from playing it. Instead, they can
+/// only fork it once again and play that fork (see `Playthrough::fork_and_play()`). This is to
+/// prevent other children from being effectively disconnected from their parent.
+#[derive(Debug, new)]
+pub struct Playthrough {
+    /// The name of this `Playthrough`.
+    ///
+    /// Also used as the name of the file it is stored in (see `PlaythroughStore`).
+    pub(super) name: String,
+    /// The rules this `Playthrough` was created with.
+    #[new(default)]
+    pub(super) settings: PlaythroughSettings,
+    /// The last time any `Save` of this `Playthrough` was played, if ever.
+    #[new(default)]
+    pub(super) last_played: Option<DateTime<Utc>>,
+
+    /// The first save.
+    ///
+    /// Also the only one without a parent.
+    #[new(default)]
+    pub(super) root_save: Option<SaveId>,
+
+    /// Save storage.
+    #[new(default)]
+    pub(super) arena: Arena<SaveEntry>,
+}
+
+impl Playthrough {
+    /// Get the name of this `Playthrough`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Rename this `Playthrough`.
+    ///
+    /// This doesn't touch its stored file, see `PlaythroughStore::rename()` for that.
+    pub fn set_name(
+        &mut self,
+        name: String,
+    ) {
+        self.name = name;
+    }
+
+    /// Get the rules this `Playthrough` was created with.
+    pub const fn settings(&self) -> PlaythroughSettings {
+        self.settings
+    }
+
+    /// Set the rules of this `Playthrough`. Only meant to be called before its root `Save` is made.
+    pub const fn set_settings(
+        &mut self,
+        settings: PlaythroughSettings,
+    ) {
+        self.settings = settings;
+    }
+
+    /// Get the time when this `Playthrough` was created, which is the birth time of its root
+    /// `Save`. `None` if no root `Save` was made yet.
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.root_save
+            .and_then(|root_save: SaveId| self.get_save(root_save).ok())
+            .map(SaveEntry::get_birth_time)
+    }
+
+    /// Get the playtime of every `Save` in this `Playthrough` (deleted or not) added together.
+    pub fn total_playtime(&self) -> Duration {
+        self.arena
+            .iter()
+            .filter(|node: &&Node<SaveEntry>| !node.is_removed())
+            .map(|node: &Node<SaveEntry>| node.get().get_playtime())
+            .sum()
+    }
+
+    /// Get the last time any `Save` of this `Playthrough` was played, if ever.
+    pub const fn last_played(&self) -> Option<DateTime<Utc>> {
+        self.last_played
+    }
+
+    /// Set the last time this `Playthrough` was played to now.
+    pub fn mark_played(&mut self) {
+        self.last_played = Some(Utc::now());
+    }
+
+    /// Get the `Node` which this `SaveId` points to as a reference.
+    ///
+    /// # Errors
+    /// If the passed `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn get_save_node(
+        &self,
+        id: SaveId,
+    ) -> Result<&Node<SaveEntry>> {
+        self.arena
+            .get(*id)
+            .filter(|_: &&Node<SaveEntry>| !id.is_removed(&self.arena))
+            .ok_or_eyre(format!(
+                "Passed `SaveId` (with value {}) doesn't point to a `Node`.",
+                *id
+            ))
+    }
+
+    /// Get the `SaveEntry` which this `SaveId` points to as a reference.
+    ///
+    /// # Errors
+    /// If the passed `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn get_save(
+        &self,
+        id: SaveId,
+    ) -> Result<&SaveEntry> {
+        Ok(self.get_save_node(id)?.get())
+    }
+
+    /// Get the `Node` which this `SaveId` points to as a mutable reference.
+    ///
+    /// # Errors
+    /// If the passed `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn get_save_node_mut(
+        &mut self,
+        id: SaveId,
+    ) -> Result<&mut Node<SaveEntry>> {
+        let _: &Node<SaveEntry> = self.get_save_node(id)?;
+        self.arena.get_mut(*id).ok_or_eyre(format!(
+            "Passed ID (with value {}) doesn't point to anything.",
+            *id
+        ))
+    }
+
+    /// Get the `SaveEntry` which this `SaveId` points to as a mutable reference.
+    ///
+    /// # Errors
+    /// If the passed `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn get_save_mut(
+        &mut self,
+        id: SaveId,
+    ) -> Result<&mut SaveEntry> {
+        Ok(self.get_save_node_mut(id)?.get_mut())
+    }
+
+    /// Make a root `Save`.
+    ///
+    /// # Errors
+    /// If a root `Save` already exists.
+    #[cfg_attr(feature = "logging", instrument)]
+    pub fn make_root_node(&mut self) -> Result<SaveId> {
+        if self.root_save.is_some() {
+            bail_log!("Root save already exists.");
+        }
+        let root_id: SaveId = self.arena.new_node(SaveEntry::Active(Save::new())).into();
+        self.root_save = Some(root_id);
+        Ok(root_id)
+    }
+
+    /// Fork a `Save` from the one this `SaveId` points to.
+    ///
+    /// The fork is appended as the last child of its parent, and the parent `Save` gets sealed,
+    /// if it wasn't already.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    /// If this `SaveId` points to a deleted save.
+    /// If appending the fork to its parent fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn fork_save(
+        &mut self,
+        parent_id: SaveId,
+    ) -> Result<SaveId> {
+        let SaveEntry::Active(ref mut parent) = *self.get_save_mut(parent_id)? else {
+            bail_log!(
+                "Passed ID (with value {}) points to a deleted save.",
+                *parent_id
+            )
+        };
+        parent.seal();
+        let child: Save = parent.mk_child();
+        let child_id: SaveId = self.arena.new_node(SaveEntry::Active(child)).into();
+        parent_id.checked_append(*child_id, &mut self.arena)?;
+        Ok(child_id)
+    }
+
+    /// Delete a `Save`.
+    /// That is done by having the node point to `SaveEntry::Trashed` instead of
+    /// `SaveEntry::Active`.
+    /// Until the trash is compacted (see `Playthrough::compact_trash()`), the save can be restored
+    /// with `Playthrough::restore_save()`. After that, only its metadata is kept.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    /// If this `SaveId` points to a deleted save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn delete_save(
+        &mut self,
+        id: SaveId,
+    ) -> Result<()> {
+        let SaveEntry::Active(ref active_save) = *self.get_save(id)? else {
+            bail_log!(
+                "Passed ID (with value {}) already points to a deleted save.",
+                *id
+            )
+        };
+        *self.get_save_mut(id)? = SaveEntry::Trashed(TrashedSave::new(active_save.clone()));
+        Ok(())
+    }
+}
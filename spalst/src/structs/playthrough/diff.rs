@@ -0,0 +1,118 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::SaveChange,
+    structs::{Playthrough, Save, SaveDiff, SaveId},
+};
+use color_eyre::eyre::{OptionExt as _, Result, WrapErr as _};
+use serde_json::Value;
+use std::collections::BTreeSet;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Find what changed between the saves these `SaveId`s point to.
+    ///
+    /// Both `Save`s are compared as they are serialized, so everything they keep is compared,
+    /// including the game itself. Saves in the trash can be compared as well.
+    ///
+    /// # Errors
+    /// If either `SaveId` doesn't point to a save, or points to a save that was deleted permanently.
+    /// If serializing either `Save` fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn diff(
+        &self,
+        from: SaveId,
+        to: SaveId,
+    ) -> Result<SaveDiff> {
+        let before: Value = serde_json::to_value(self.kept_save(from)?).wrap_err("Failed to serialize the older save")?;
+        let after: Value = serde_json::to_value(self.kept_save(to)?).wrap_err("Failed to serialize the newer save")?;
+        let mut changes: Vec<SaveChange> = Vec::new();
+        Self::diff_values("", &before, &after, &mut changes);
+        Ok(SaveDiff {
+            from,
+            to,
+            common_ancestor: self.common_ancestor(from, to)?,
+            changes,
+        })
+    }
+
+    /// Get the `Save` kept by the save this `SaveId` points to (see `SaveEntry::save()`).
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save, or points to a save that was deleted permanently.
+    fn kept_save(
+        &self,
+        id: SaveId,
+    ) -> Result<&Save> {
+        // Not logged, since it is shown to the user instead (see `SaveDiffScreen`).
+        self.get_save(id)?.save().ok_or_eyre(format!(
+            "Passed ID (with value {}) points to a permanently deleted save, which doesn't keep the game.",
+            *id
+        ))
+    }
+
+    /// Push every difference between these two values to `changes`. `path` is the JSON pointer
+    /// both of them are at.
+    ///
+    /// Objects are compared key by key, and arrays index by index. Any other values are only
+    /// compared as a whole.
+    fn diff_values(
+        path: &str,
+        before: &Value,
+        after: &Value,
+        changes: &mut Vec<SaveChange>,
+    ) {
+        if let (Some(before_object), Some(after_object)) = (before.as_object(), after.as_object()) {
+            let keys: BTreeSet<&String> = before_object.keys().chain(after_object.keys()).collect();
+            for key in keys {
+                Self::diff_entries(
+                    format!("{path}/{}", key.replace('~', "~0").replace('/', "~1")),
+                    before_object.get(key),
+                    after_object.get(key),
+                    changes,
+                );
+            }
+        } else if let (Some(before_array), Some(after_array)) = (before.as_array(), after.as_array()) {
+            for index in 0..before_array.len().max(after_array.len()) {
+                Self::diff_entries(
+                    format!("{path}/{index}"),
+                    before_array.get(index),
+                    after_array.get(index),
+                    changes,
+                );
+            }
+        } else if before != after {
+            changes.push(SaveChange::Changed {
+                path: path.to_owned(),
+                before: before.clone(),
+                after: after.clone(),
+            });
+        } else {
+            // Equal values that aren't objects or arrays have nothing to compare inside them.
+        }
+    }
+
+    /// Push every difference between the values at `path`, either of which may be missing, to
+    /// `changes`.
+    fn diff_entries(
+        path: String,
+        before: Option<&Value>,
+        after: Option<&Value>,
+        changes: &mut Vec<SaveChange>,
+    ) {
+        match (before, after) {
+            (Some(before_value), Some(after_value)) => Self::diff_values(&path, before_value, after_value, changes),
+            (Some(before_value), None) => changes.push(SaveChange::Removed {
+                path,
+                value: before_value.clone(),
+            }),
+            (None, Some(after_value)) => changes.push(SaveChange::Added {
+                path,
+                value: after_value.clone(),
+            }),
+            (None, None) => {}
+        }
+    }
+}
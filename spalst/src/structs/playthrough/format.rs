@@ -0,0 +1,224 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    enums::SaveEntry,
+    structs::{MigrationReport, Playthrough, Save, SaveId, StoredPlaythrough, StoredSave},
+};
+use color_eyre::eyre::{OptionExt as _, Result};
+use core::str;
+use indextree::{Arena, NodeId};
+use serde_json::Value;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// The version of the on-disk format that `Playthrough::to_bytes()` writes.
+    ///
+    /// Bump this whenever `StoredPlaythrough` (or anything it contains) changes shape, and register
+    /// a `Migration` from the previous version in `Playthrough::MIGRATIONS`.
+    pub const FORMAT_VERSION: u32 = 6;
+    /// The first word of every stored `Playthrough`.
+    pub(super) const FORMAT_MAGIC: &str = "spalst-playthrough";
+    /// The highest index a stored save may have. `Playthrough::from_stored()` makes room for every
+    /// index up to the highest one, so this keeps a corrupted file from making it allocate more
+    /// than any playthrough could need.
+    pub(super) const MAX_SAVE_INDEX: usize = 1 << 20;
+
+    /// Serialize this `Playthrough`.
+    ///
+    /// The format is a single header line, `spalst-playthrough <FORMAT_VERSION>`, followed by a
+    /// `StoredPlaythrough` as JSON.
+    ///
+    /// # Errors
+    /// If computing the checksum of a save or serializing to JSON fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Self::with_header(Self::FORMAT_MAGIC, &self.to_stored()?)
+    }
+
+    /// Deserialize a `Playthrough` that was serialized with `Playthrough::to_bytes()`, and return
+    /// what upgrading the file to the current `FORMAT_VERSION` changed, if it was written with an
+    /// older one (see `Playthrough::MIGRATIONS`).
+    ///
+    /// # Errors
+    /// If the header is missing or malformed.
+    /// If the file was written with a newer `FORMAT_VERSION`, or upgrading it fails.
+    /// If the body isn't a valid `StoredPlaythrough`.
+    /// If the stored saves don't form a valid arena (see `Playthrough::from_stored()`).
+    #[cfg_attr(feature = "logging", instrument(skip(bytes)))]
+    pub fn from_bytes_migrated(bytes: &[u8]) -> Result<(Self, MigrationReport)> {
+        let (stored, report): (StoredPlaythrough, MigrationReport) = Self::without_header(bytes, Self::FORMAT_MAGIC)?;
+        Ok((Self::from_stored(stored)?, report))
+    }
+
+    /// Serialize a `StoredPlaythrough` as JSON, after a `<magic> <FORMAT_VERSION>` header line.
+    ///
+    /// # Errors
+    /// If serializing to JSON fails.
+    pub(super) fn with_header(
+        magic: &str,
+        stored: &StoredPlaythrough,
+    ) -> Result<Vec<u8>> {
+        let mut bytes: Vec<u8> = format!("{magic} {}\n", Self::FORMAT_VERSION).into_bytes();
+        serde_json::to_writer_pretty(&mut bytes, stored)?;
+        Ok(bytes)
+    }
+
+    /// Deserialize a `StoredPlaythrough` that was serialized with `Playthrough::with_header()`,
+    /// upgrading it to the current `FORMAT_VERSION` first (see `Playthrough::migrate()`).
+    ///
+    /// # Errors
+    /// If the header is missing or malformed (see `Playthrough::split_header()`).
+    /// If the bytes were written with a newer `FORMAT_VERSION`, or upgrading them fails.
+    /// If the body isn't a valid `StoredPlaythrough`.
+    pub(super) fn without_header(
+        bytes: &[u8],
+        magic: &str,
+    ) -> Result<(StoredPlaythrough, MigrationReport)> {
+        let (version, body): (u32, &[u8]) = Self::split_header(bytes, magic)?;
+        let mut body: Value = serde_json::from_slice(body)?;
+        let report: MigrationReport = Self::migrate(&mut body, version, Self::MIGRATIONS)?;
+        Ok((serde_json::from_value(body)?, report))
+    }
+
+    /// Split stored bytes into the format version from the header and the body after it.
+    ///
+    /// # Errors
+    /// If the header is missing, isn't valid UTF-8, doesn't start with `magic` or has an invalid
+    /// version.
+    fn split_header<'bytes>(
+        bytes: &'bytes [u8],
+        magic: &str,
+    ) -> Result<(u32, &'bytes [u8])> {
+        let newline: usize = bytes
+            .iter()
+            .position(|byte: &u8| *byte == b'\n')
+            .ok_or_eyre("Stored playthrough has no header line.")?;
+        let (header, body): (&[u8], &[u8]) = bytes.split_at(newline);
+        let Some((found_magic, version)) = str::from_utf8(header)?.split_once(' ') else {
+            bail_log!("Stored playthrough has a malformed header.");
+        };
+        if found_magic != magic {
+            bail_log!("Stored playthrough starts with \"{found_magic}\" instead of \"{magic}\".");
+        }
+        Ok((version.parse()?, body))
+    }
+
+    /// Flatten this `Playthrough` into a `StoredPlaythrough`.
+    ///
+    /// Removed nodes are skipped. Every tree in the arena is walked in pre-order, so parents are
+    /// always stored before their children and siblings keep their order.
+    ///
+    /// # Errors
+    /// If computing the checksum of a save fails (see `StoredSave::try_new()`).
+    pub(super) fn to_stored(&self) -> Result<StoredPlaythrough> {
+        let mut saves: Vec<StoredSave> = Vec::with_capacity(self.arena.count());
+        for tree_root in self
+            .arena
+            .iter_node_ids()
+            .filter(|id: &NodeId| self.arena[*id].parent().is_none())
+        {
+            for id in tree_root.descendants(&self.arena) {
+                saves.push(StoredSave::try_new(
+                    id.into(),
+                    self.arena[id].parent().map(Into::into),
+                    self.arena[id].get().clone(),
+                )?);
+            }
+        }
+
+        Ok(StoredPlaythrough {
+            name: self.name.clone(),
+            settings: self.settings,
+            last_played: self.last_played,
+            root_save: self.root_save.map(|root_save: SaveId| (*root_save).into()),
+            saves,
+        })
+    }
+
+    /// Rebuild a `Playthrough` from a `StoredPlaythrough`.
+    ///
+    /// Every `SaveId` keeps the index it had when it was stored. Indexes that weren't stored
+    /// (because their node was removed) are recreated as removed nodes.
+    ///
+    /// # Errors
+    /// If two stored saves have the same index, or one has the index 0 or one above
+    /// `Playthrough::MAX_SAVE_INDEX`.
+    /// If a stored save doesn't match its checksum.
+    /// If a parent or the root save points to an index that wasn't stored.
+    /// If linking a save to its parent fails (for example, because it would make a cycle).
+    #[cfg_attr(feature = "logging", instrument(skip(stored)))]
+    pub(super) fn from_stored(stored: StoredPlaythrough) -> Result<Self> {
+        let len: usize = stored
+            .saves
+            .iter()
+            .map(|save: &StoredSave| save.id)
+            .max()
+            .unwrap_or_default();
+        if len > Self::MAX_SAVE_INDEX {
+            bail_log!(
+                "Stored save has the index {len}, which is above the highest allowed one ({}).",
+                Self::MAX_SAVE_INDEX
+            );
+        }
+        let mut entries: Vec<Option<SaveEntry>> = vec![None; len];
+        let mut links: Vec<(usize, usize)> = Vec::new();
+        for save in stored.saves {
+            if !save.has_valid_checksum()? {
+                bail_log!("Stored save {} doesn't match its checksum.", save.id);
+            }
+            let Some(slot) = save
+                .id
+                .checked_sub(1)
+                .and_then(|index: usize| entries.get_mut(index))
+            else {
+                bail_log!("Stored save has the invalid index {}.", save.id);
+            };
+            if slot.replace(save.entry).is_some() {
+                bail_log!("Multiple stored saves have the index {}.", save.id);
+            }
+            if let Some(parent) = save.parent {
+                links.push((save.id, parent));
+            }
+        }
+
+        let mut arena: Arena<SaveEntry> = Arena::with_capacity(len);
+        let mut ids: Vec<Option<NodeId>> = Vec::with_capacity(len);
+        let mut placeholders: Vec<NodeId> = Vec::new();
+        for entry in entries {
+            if let Some(entry) = entry {
+                ids.push(Some(arena.new_node(entry)));
+            } else {
+                // Keeps the indexes of the following nodes intact. Removed below.
+                placeholders.push(arena.new_node(SaveEntry::Active(Save::new())));
+                ids.push(None);
+            }
+        }
+        let node_id = |index: usize| -> Result<NodeId> {
+            index
+                .checked_sub(1)
+                .and_then(|index: usize| ids.get(index).copied().flatten())
+                .ok_or_eyre(format!(
+                    "Stored playthrough points to the missing save {index}."
+                ))
+        };
+
+        for (id, parent) in links {
+            node_id(parent)?.checked_append(node_id(id)?, &mut arena)?;
+        }
+        let root_save: Option<SaveId> = stored.root_save.map(node_id).transpose()?.map(Into::into);
+        for placeholder in placeholders {
+            placeholder.remove(&mut arena);
+        }
+
+        Ok(Self {
+            name: stored.name,
+            settings: stored.settings,
+            last_played: stored.last_played,
+            root_save,
+            arena,
+        })
+    }
+}
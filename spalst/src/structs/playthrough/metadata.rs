@@ -0,0 +1,43 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::SaveEntry,
+    structs::{Playthrough, SaveFilter, SaveId, SaveMetadata},
+};
+use color_eyre::eyre::Result;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Replace what the user wrote down about the save this `SaveId` points to. Deleted saves
+    /// keep their `SaveMetadata` (see `DeletedSave`), so theirs can be changed as well.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn set_metadata(
+        &mut self,
+        id: SaveId,
+        metadata: SaveMetadata,
+    ) -> Result<()> {
+        *self.get_save_mut(id)?.metadata_mut() = metadata;
+        Ok(())
+    }
+
+    /// Find every save (deleted or not) whose `SaveMetadata` matches this `SaveFilter`, ordered by
+    /// their `SaveId`.
+    pub fn search(
+        &self,
+        filter: &SaveFilter,
+    ) -> Vec<SaveId> {
+        self.arena
+            .iter_node_ids()
+            .map(SaveId::from)
+            .filter(|id: &SaveId| {
+                self.get_save(*id)
+                    .is_ok_and(|entry: &SaveEntry| filter.matches(entry.metadata()))
+            })
+            .collect()
+    }
+}
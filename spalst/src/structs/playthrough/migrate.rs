@@ -0,0 +1,244 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    structs::{Migration, MigrationReport, Playthrough, PlaythroughSettings, StoredSave},
+};
+use color_eyre::eyre::{OptionExt as _, Result, WrapErr as _};
+use serde_json::{Map, Value, json};
+use std::collections::HashSet;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Every registered `Migration`, ordered by the format version they upgrade from.
+    ///
+    /// Whenever `Playthrough::FORMAT_VERSION` is bumped, a `Migration` from the previous version
+    /// has to be added here, so that files written before the bump can still be loaded.
+    pub const MIGRATIONS: &[Migration] = &[
+        Migration {
+            from: 1,
+            description: "Store whether every save was forked.",
+            upgrade: Self::store_sealed,
+        },
+        Migration {
+            from: 2,
+            description: "Store the checksum of every save.",
+            upgrade: Self::store_checksums,
+        },
+        Migration {
+            from: 3,
+            description: "Store whether every save was made by an autosave.",
+            upgrade: Self::store_autosave,
+        },
+        Migration {
+            from: 4,
+            description: "Give every save empty metadata.",
+            upgrade: Self::store_metadata,
+        },
+        Migration {
+            from: 5,
+            description: "Give the playthrough the default settings.",
+            upgrade: Self::store_settings,
+        },
+    ];
+
+    /// Upgrade the JSON body of a `Playthrough` stored with this format version to
+    /// `Playthrough::FORMAT_VERSION`, by applying the fitting `migrations` one after another.
+    ///
+    /// # Errors
+    /// If the version is newer than `Playthrough::FORMAT_VERSION`.
+    /// If no `Migration` upgrades from one of the versions in between.
+    /// If one of the `Migration`s fails.
+    #[cfg_attr(feature = "logging", instrument(skip(body, migrations)))]
+    pub(super) fn migrate(
+        body: &mut Value,
+        version: u32,
+        migrations: &[Migration],
+    ) -> Result<MigrationReport> {
+        if version > Self::FORMAT_VERSION {
+            bail_log!(
+                "Stored playthrough has format version {version}, but only versions up to {} are supported.",
+                Self::FORMAT_VERSION
+            );
+        }
+
+        let mut report: MigrationReport = MigrationReport {
+            from: version,
+            to: Self::FORMAT_VERSION,
+            applied: Vec::new(),
+            changes: Vec::new(),
+        };
+        let mut current: u32 = version;
+        while current < Self::FORMAT_VERSION {
+            let Some(migration) = migrations
+                .iter()
+                .find(|migration: &&Migration| migration.from == current)
+            else {
+                bail_log!("Stored playthrough has format version {current}, which can't be upgraded.");
+            };
+            report
+                .changes
+                .extend((migration.upgrade)(body).wrap_err_with(|| format!("Failed to upgrade from format version {current}"))?);
+            report.applied.push(migration.description);
+            current = current.saturating_add(1);
+        }
+        Ok(report)
+    }
+
+    /// Upgrade from format version 1, in which saves didn't store whether they were forked.
+    ///
+    /// Every save with children was forked, so it is sealed. Every other one isn't.
+    ///
+    /// # Errors
+    /// If the body isn't shaped like a version 1 `StoredPlaythrough`.
+    fn store_sealed(body: &mut Value) -> Result<Vec<String>> {
+        let saves: &mut Vec<Value> = Self::stored_saves(body)?;
+        let parents: HashSet<u64> = saves
+            .iter()
+            .filter_map(|stored_save: &Value| stored_save.get("parent")?.as_u64())
+            .collect();
+
+        let mut changes: Vec<String> = Vec::new();
+        for stored_save in saves {
+            let id: u64 = stored_save
+                .get("id")
+                .and_then(Value::as_u64)
+                .ok_or_eyre("Stored save has no index.")?;
+            // Permanently deleted saves don't keep their game data, so there is nothing to seal.
+            let Some(save) = Self::stored_save_data(stored_save) else {
+                continue;
+            };
+
+            let forked: bool = parents.contains(&id);
+            if forked && !Self::is_stored_sealed(save) {
+                changes.push(format!("Save {id} was forked, so it is sealed."));
+            }
+            let sealed: bool = forked || Self::is_stored_sealed(save);
+            let _: Option<Value> = save.insert("sealed".to_owned(), Value::Bool(sealed));
+        }
+        Ok(changes)
+    }
+
+    /// Upgrade from format version 2, in which saves didn't store a checksum.
+    ///
+    /// Whatever the saves contain is trusted, since there is nothing to check it against.
+    ///
+    /// # Errors
+    /// If the body isn't shaped like a version 2 `StoredPlaythrough`.
+    fn store_checksums(body: &mut Value) -> Result<Vec<String>> {
+        for stored_save in Self::stored_saves(body)? {
+            Self::refresh_checksum(stored_save)?;
+        }
+        Ok(Vec::new())
+    }
+
+    /// Upgrade from format version 3, in which saves didn't store whether they were made by an
+    /// autosave. None of them were, since autosaves didn't exist yet.
+    ///
+    /// # Errors
+    /// If the body isn't shaped like a version 3 `StoredPlaythrough`.
+    fn store_autosave(body: &mut Value) -> Result<Vec<String>> {
+        for stored_save in Self::stored_saves(body)? {
+            if let Some(save) = Self::stored_save_data(stored_save) {
+                let _: Option<Value> = save.insert("autosave".to_owned(), Value::Bool(false));
+            }
+            Self::refresh_checksum(stored_save)?;
+        }
+        Ok(Vec::new())
+    }
+
+    /// Upgrade from format version 4, in which saves had no `SaveMetadata`.
+    ///
+    /// # Errors
+    /// If the body isn't shaped like a version 4 `StoredPlaythrough`.
+    fn store_metadata(body: &mut Value) -> Result<Vec<String>> {
+        for stored_save in Self::stored_saves(body)? {
+            let metadata: Value = json!({
+                "name": "",
+                "notes": "",
+                "tags": [],
+                "pinned": false,
+            });
+            if let Some(save) = Self::stored_save_data(stored_save) {
+                let _: Option<Value> = save.insert("metadata".to_owned(), metadata);
+            } else if let Some(deleted_save) = stored_save
+                .pointer_mut("/entry/Deleted")
+                .and_then(Value::as_object_mut)
+            {
+                let _: Option<Value> = deleted_save.insert("metadata".to_owned(), metadata);
+            } else {
+                bail_log!("Stored save has an unknown kind of entry.");
+            }
+            Self::refresh_checksum(stored_save)?;
+        }
+        Ok(Vec::new())
+    }
+
+    /// Upgrade from format version 5, in which `Playthrough`s had no `PlaythroughSettings`.
+    ///
+    /// They were all played with the default rules, and no seed was ever used.
+    ///
+    /// # Errors
+    /// If the body isn't an object.
+    fn store_settings(body: &mut Value) -> Result<Vec<String>> {
+        let settings: Value = serde_json::to_value(PlaythroughSettings::default())?;
+        let _: Option<Value> = body
+            .as_object_mut()
+            .ok_or_eyre("Stored playthrough isn't an object.")?
+            .insert("settings".to_owned(), settings);
+        Ok(Vec::new())
+    }
+
+    /// Get the list of stored saves from the body of a stored `Playthrough`.
+    ///
+    /// # Errors
+    /// If the body has no list of saves.
+    fn stored_saves(body: &mut Value) -> Result<&mut Vec<Value>> {
+        body.get_mut("saves")
+            .and_then(Value::as_array_mut)
+            .ok_or_eyre("Stored playthrough has no list of saves.")
+    }
+
+    /// Get the `Save` kept by a stored save, whether it is active or in the trash. `None` if it was
+    /// deleted permanently.
+    fn stored_save_data(stored_save: &mut Value) -> Option<&mut Map<String, Value>> {
+        let pointer: &str = if stored_save.pointer("/entry/Trashed").is_some() {
+            "/entry/Trashed/save"
+        } else {
+            "/entry/Active"
+        };
+        stored_save
+            .pointer_mut(pointer)
+            .and_then(Value::as_object_mut)
+    }
+
+    /// Recompute the checksum of a stored save, after its entry was changed.
+    ///
+    /// Every `Migration` that changes entries from format version 3 onwards has to call this, or
+    /// the entries won't match their checksums anymore.
+    ///
+    /// # Errors
+    /// If the stored save isn't an object or has no entry.
+    /// If computing the checksum fails.
+    fn refresh_checksum(stored_save: &mut Value) -> Result<()> {
+        let stored_save: &mut Map<String, Value> = stored_save
+            .as_object_mut()
+            .ok_or_eyre("Stored save isn't an object.")?;
+        let checksum: u64 = StoredSave::checksum_of(
+            stored_save
+                .get("entry")
+                .ok_or_eyre("Stored save has no entry.")?,
+        )?;
+        let _: Option<Value> = stored_save.insert("checksum".to_owned(), checksum.into());
+        Ok(())
+    }
+
+    /// Check whether a stored save is marked as sealed.
+    fn is_stored_sealed(save: &Map<String, Value>) -> bool {
+        save.get("sealed")
+            .and_then(Value::as_bool)
+            .unwrap_or_default()
+    }
+}
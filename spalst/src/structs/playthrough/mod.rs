@@ -0,0 +1,21 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+mod archive;
+mod autosave;
+mod def;
+mod diff;
+mod format;
+mod metadata;
+mod migrate;
+mod play;
+mod playtime;
+mod prune;
+mod resume;
+#[cfg(test)]
+mod tests;
+mod trash;
+mod tree;
+mod verify;
+
+pub use def::Playthrough;
@@ -0,0 +1,70 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    enums::SaveEntry,
+    structs::{Playthrough, SaveId},
+};
+use color_eyre::eyre::Result;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Check whether the save this `SaveId` points to is sealed, meaning that it was forked and
+    /// can't be played anymore. Deleted saves are never sealed.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn is_sealed(
+        &self,
+        id: SaveId,
+    ) -> Result<bool> {
+        Ok(match *self.get_save(id)? {
+            SaveEntry::Active(ref save) => save.is_sealed(),
+            SaveEntry::Trashed(_) | SaveEntry::Deleted(_) => false,
+        })
+    }
+
+    /// Start playing the save this `SaveId` points to.
+    ///
+    /// Sealed saves can't be played, use `Playthrough::fork_and_play()` for those instead.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    /// If this `SaveId` points to a deleted save.
+    /// If this `SaveId` points to a sealed save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn play_save(
+        &mut self,
+        id: SaveId,
+    ) -> Result<()> {
+        let SaveEntry::Active(ref save) = *self.get_save(id)? else {
+            bail_log!("Passed ID (with value {}) points to a deleted save.", *id);
+        };
+        if save.is_sealed() {
+            bail_log!(
+                "Passed ID (with value {}) points to a sealed save. Fork it and play the fork instead.",
+                *id
+            );
+        }
+        self.mark_played();
+        Ok(())
+    }
+
+    /// Fork the save this `SaveId` points to, and start playing the fork. Returns the `SaveId` of
+    /// the fork.
+    ///
+    /// # Errors
+    /// If forking fails (see `Playthrough::fork_save()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn fork_and_play(
+        &mut self,
+        id: SaveId,
+    ) -> Result<SaveId> {
+        let child_id: SaveId = self.fork_save(id)?;
+        self.play_save(child_id)?;
+        Ok(child_id)
+    }
+}
@@ -0,0 +1,49 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    enums::SaveEntry,
+    structs::{Playthrough, SaveId},
+};
+use color_eyre::eyre::Result;
+use core::time::Duration;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Add this much time to the playtime of the save this `SaveId` points to.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    /// If this `SaveId` points to a deleted save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn add_playtime(
+        &mut self,
+        id: SaveId,
+        playtime: Duration,
+    ) -> Result<()> {
+        let SaveEntry::Active(ref mut save) = *self.get_save_mut(id)? else {
+            bail_log!("Passed ID (with value {}) points to a deleted save.", *id);
+        };
+        save.add_playtime(playtime);
+        Ok(())
+    }
+
+    /// Get the playtime of the save this `SaveId` points to, including the playtime of all of its
+    /// ancestors (deleted or not). This is how long it took to get to where the save is now.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn playtime_with_ancestors(
+        &self,
+        id: SaveId,
+    ) -> Result<Duration> {
+        self.path_from_root(id)?
+            .into_iter()
+            .try_fold(Duration::ZERO, |total: Duration, save_id: SaveId| {
+                Ok(total.saturating_add(self.get_save(save_id)?.get_playtime()))
+            })
+    }
+}
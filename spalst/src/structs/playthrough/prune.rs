@@ -0,0 +1,65 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::SaveEntry,
+    info,
+    structs::{Playthrough, PruneReport, SaveId},
+};
+use indextree::NodeId;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Remove every branch of the tree in which every save was deleted and compacted
+    /// (`SaveEntry::Deleted`) from the arena.
+    ///
+    /// A `DeletedSave` is kept as long as it has a descendant that isn't a `SaveEntry::Deleted`,
+    /// so the tree above every save that can still be played or restored stays intact. The root
+    /// save is never removed.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn prune(&mut self) -> PruneReport {
+        let mut branches: Vec<SaveId> = Vec::new();
+        for tree_root in self.tree_roots() {
+            self.find_prunable(tree_root, &mut branches);
+        }
+
+        let mut report: PruneReport = PruneReport::default();
+        for branch in branches {
+            for id in branch.descendants(&self.arena) {
+                report.removed = report.removed.saturating_add(1);
+                report.dropped_playtime = report
+                    .dropped_playtime
+                    .saturating_add(self.arena[id].get().get_playtime());
+            }
+            branch.remove_subtree(&mut self.arena);
+        }
+
+        if report.removed > 0 {
+            info!(
+                "Pruned {} deleted saves from playthrough \"{}\".",
+                report.removed, self.name
+            );
+        }
+        report
+    }
+
+    /// Find the highest saves in the tree under (and including) the save this `SaveId` points to,
+    /// whose whole branch can be pruned, and add them to `branches`.
+    fn find_prunable(
+        &self,
+        id: SaveId,
+        branches: &mut Vec<SaveId>,
+    ) {
+        let fully_deleted: bool = id
+            .descendants(&self.arena)
+            .all(|descendant: NodeId| matches!(*self.arena[descendant].get(), SaveEntry::Deleted(_)));
+        if fully_deleted && self.root_save != Some(id) {
+            branches.push(id);
+            return;
+        }
+        for child in id.children(&self.arena) {
+            self.find_prunable(child.into(), branches);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::SaveEntry,
+    structs::{Playthrough, SaveId, SaveMark},
+};
+use color_eyre::eyre::{OptionExt as _, Result};
+use core::iter;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Get the `SaveMark`s of the saves on the path from the root save to the save this `SaveId`
+    /// points to, as stored in a `PlaythroughRegistry`.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save, or one of its ancestors doesn't.
+    pub fn index_path(
+        &self,
+        id: SaveId,
+    ) -> Result<Vec<SaveMark>> {
+        self.path_from_root(id)?
+            .into_iter()
+            .map(|on_path: SaveId| {
+                Ok(SaveMark {
+                    index: (*on_path).into(),
+                    birth_time: self.get_save(on_path)?.get_birth_time(),
+                })
+            })
+            .collect()
+    }
+
+    /// Find the save to continue playing from, given the `SaveMark`s of the path from the root save
+    /// to the save that was played last (see `Playthrough::index_path()`).
+    ///
+    /// The path is followed down from the root save for as long as its saves still exist. Since
+    /// the indexes of removed saves can be reused, a save only counts if it was born when its
+    /// `SaveMark` says. If the save it ends at isn't active anymore, its nearest active ancestor is
+    /// returned instead. `None` if there is none.
+    pub fn resume_point(
+        &self,
+        path: &[SaveMark],
+    ) -> Option<SaveId> {
+        let mut deepest: Option<SaveId> = None;
+        let mut candidates: Vec<SaveId> = self.root_save.into_iter().collect();
+        for mark in path {
+            let Some(found) = candidates.into_iter().find(|candidate: &SaveId| {
+                usize::from(**candidate) == mark.index
+                    && self
+                        .get_save(*candidate)
+                        .is_ok_and(|entry: &SaveEntry| entry.get_birth_time() == mark.birth_time)
+            }) else {
+                break;
+            };
+            deepest = Some(found);
+            candidates = self.children(found).unwrap_or_default();
+        }
+
+        let deepest: SaveId = deepest?;
+        iter::once(deepest)
+            .chain(self.ancestors(deepest).unwrap_or_default())
+            .find(|id: &SaveId| {
+                self.get_save(*id)
+                    .is_ok_and(|entry: &SaveEntry| !entry.is_deleted())
+            })
+    }
+
+    /// Continue playing from the save the path of `SaveMark`s leads to (see
+    /// `Playthrough::resume_point()`). Returns the `SaveId` of the save being played.
+    ///
+    /// If that save is sealed, it is forked and the fork is played instead.
+    ///
+    /// # Errors
+    /// If the path doesn't lead to any active save.
+    /// If playing or forking the save fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn resume(
+        &mut self,
+        path: &[SaveMark],
+    ) -> Result<SaveId> {
+        let id: SaveId = self.resume_point(path).ok_or_eyre(format!(
+            "Playthrough \"{}\" has no save to continue from.",
+            self.name
+        ))?;
+        if self.is_sealed(id)? {
+            return self.fork_and_play(id);
+        }
+        self.play_save(id)?;
+        Ok(id)
+    }
+}
@@ -0,0 +1,985 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+//!
+//! Unit tests for `Playthrough`.
+
+use crate::{
+    enums::{ColorTag, Difficulty, IntegrityProblem, SaveChange, SaveEntry},
+    structs::{IntegrityReport, Migration, MigrationReport, Playthrough, PlaythroughSettings, PruneReport, SaveDiff, SaveFilter, SaveId, SaveMark, SaveMetadata, StoredPlaythrough, StoredSave},
+};
+use color_eyre::eyre::Result;
+use core::time::Duration;
+use serde_json::Value;
+
+/// Make a `Playthrough` with just a root save. Returns it along with the `SaveId` of the root.
+fn with_root() -> Result<(Playthrough, SaveId)> {
+    let mut playthrough: Playthrough = Playthrough::new("test".to_owned());
+    let root: SaveId = playthrough.make_root_node()?;
+    Ok((playthrough, root))
+}
+
+/// A save that was never forked isn't sealed and can be played.
+#[test]
+fn unforked_save_is_playable() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    assert!(
+        !playthrough.is_sealed(root)?,
+        "A new root save must not be sealed."
+    );
+    playthrough.play_save(root)?;
+    assert!(
+        playthrough.last_played().is_some(),
+        "Playing a save must update `last_played`."
+    );
+    Ok(())
+}
+
+/// Forking a save seals it, but not the fork.
+#[test]
+fn forking_seals_parent() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    assert!(
+        playthrough.is_sealed(root)?,
+        "A forked save must be sealed."
+    );
+    assert!(
+        !playthrough.is_sealed(child)?,
+        "A fresh fork must not be sealed."
+    );
+    Ok(())
+}
+
+/// Sealed saves can't be played, but their forks can.
+#[test]
+fn sealed_save_is_not_playable() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    assert!(
+        playthrough.play_save(root).is_err(),
+        "Playing a sealed save must fail."
+    );
+    assert!(
+        playthrough.last_played().is_none(),
+        "A refused play must not update `last_played`."
+    );
+    playthrough.play_save(child)?;
+    Ok(())
+}
+
+/// A save stays sealed after it is forked again, and after its forks are deleted.
+#[test]
+fn seal_is_permanent() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let first: SaveId = playthrough.fork_save(root)?;
+    let second: SaveId = playthrough.fork_save(root)?;
+    playthrough.delete_save(first)?;
+    playthrough.delete_save(second)?;
+    assert!(
+        playthrough.is_sealed(root)?,
+        "Deleting forks must not unseal their parent."
+    );
+    assert!(
+        playthrough.play_save(root).is_err(),
+        "Playing a sealed save must fail."
+    );
+    Ok(())
+}
+
+/// `Playthrough::fork_and_play()` works on sealed saves, and seals nothing but its parent.
+#[test]
+fn fork_and_play_on_sealed_save() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let _: SaveId = playthrough.fork_save(root)?;
+    let played: SaveId = playthrough.fork_and_play(root)?;
+    assert_ne!(played, root, "`fork_and_play()` must play a new save.");
+    assert!(
+        !playthrough.is_sealed(played)?,
+        "The played fork must not be sealed."
+    );
+    assert!(
+        playthrough.last_played().is_some(),
+        "`fork_and_play()` must update `last_played`."
+    );
+    Ok(())
+}
+
+/// Deleted saves can't be played or forked, and are never sealed.
+#[test]
+fn deleted_save_is_not_playable() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    playthrough.delete_save(root)?;
+    assert!(
+        !playthrough.is_sealed(root)?,
+        "A deleted save must not be sealed."
+    );
+    assert!(
+        playthrough.play_save(root).is_err(),
+        "Playing a deleted save must fail."
+    );
+    assert!(
+        playthrough.fork_and_play(root).is_err(),
+        "Forking a deleted save must fail."
+    );
+    Ok(())
+}
+
+/// The seal survives being stored and loaded again.
+#[test]
+fn seal_survives_round_trip() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    let loaded: Playthrough = Playthrough::from_bytes_migrated(&playthrough.to_bytes()?)?.0;
+    assert!(loaded.is_sealed(root)?, "The seal must be stored.");
+    assert!(
+        !loaded.is_sealed(child)?,
+        "Unsealed saves must stay unsealed."
+    );
+    Ok(())
+}
+
+/// Check that the root save is the only save without a parent, and that every save descends from
+/// it.
+fn assert_root_invariant(playthrough: &Playthrough) -> Result<()> {
+    let root: Option<SaveId> = playthrough.root_save;
+    assert!(root.is_some(), "The root save must exist.");
+    assert_eq!(
+        playthrough.tree_roots(),
+        root.into_iter().collect::<Vec<SaveId>>(),
+        "The root save must be the only save without a parent."
+    );
+    for id in playthrough.tree_roots() {
+        for descendant in playthrough.descendants(id)? {
+            assert_eq!(
+                playthrough.path_from_root(descendant)?.first().copied(),
+                root,
+                "Every save must descend from the root save."
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Forks are attached to their parent, in the order they were made.
+#[test]
+fn fork_is_attached_to_parent() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let first: SaveId = playthrough.fork_save(root)?;
+    let second: SaveId = playthrough.fork_save(root)?;
+    assert_eq!(
+        playthrough.children(root)?,
+        vec![first, second],
+        "Forks must be the children of their parent, oldest first."
+    );
+    assert_eq!(
+        playthrough.ancestors(first)?,
+        vec![root],
+        "A fork must have its parent as its only ancestor."
+    );
+    assert_eq!(
+        playthrough.tree_roots(),
+        vec![root],
+        "Forks must not become roots."
+    );
+    Ok(())
+}
+
+/// The tree-walk methods agree with each other on a small tree.
+#[test]
+fn tree_walk() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let left: SaveId = playthrough.fork_save(root)?;
+    let right: SaveId = playthrough.fork_save(root)?;
+    let left_child: SaveId = playthrough.fork_save(left)?;
+    let grandchild: SaveId = playthrough.fork_save(left_child)?;
+
+    assert_eq!(
+        playthrough.descendants(root)?,
+        vec![left, left_child, grandchild, right],
+        "Descendants must be in pre-order."
+    );
+    assert_eq!(
+        playthrough.ancestors(grandchild)?,
+        vec![left_child, left, root],
+        "Ancestors must go from the parent to the root."
+    );
+    assert_eq!(
+        playthrough.path_from_root(grandchild)?,
+        vec![root, left, left_child, grandchild],
+        "The path must go from the root to the save."
+    );
+    assert_eq!(
+        playthrough.depth(root)?,
+        0,
+        "The root must have a depth of 0."
+    );
+    assert_eq!(
+        playthrough.depth(grandchild)?,
+        3,
+        "Depth must count the ancestors."
+    );
+    assert!(
+        playthrough.descendants(right)?.is_empty(),
+        "A leaf must have no descendants."
+    );
+    Ok(())
+}
+
+/// The root save invariant holds after a long sequence of forks and deletes, and after being
+/// stored and loaded again.
+#[test]
+fn root_invariant_after_forks_and_deletes() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let mut saves: Vec<SaveId> = vec![root];
+    // A small linear congruential generator, so the sequence is arbitrary but reproducible.
+    let mut seed: u64 = 0x5EED;
+    for _ in 0..200_u8 {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let Some(&id) = saves.get(usize::try_from(seed >> 33_i32)?.rem_euclid(saves.len())) else {
+            continue;
+        };
+        if seed.rem_euclid(4) == 0 {
+            // Deleting an already deleted save fails, which must leave the tree untouched.
+            let _: Result<()> = playthrough.delete_save(id);
+        } else if let Ok(child) = playthrough.fork_save(id) {
+            assert_eq!(
+                playthrough.ancestors(child)?.first().copied(),
+                Some(id),
+                "A fork must be a child of the save it was forked from."
+            );
+            saves.push(child);
+        } else {
+            // Only deleted saves can't be forked.
+        }
+        assert_root_invariant(&playthrough)?;
+    }
+
+    let loaded: Playthrough = Playthrough::from_bytes_migrated(&playthrough.to_bytes()?)?.0;
+    assert_root_invariant(&loaded)?;
+    for id in saves {
+        assert_eq!(
+            loaded.path_from_root(id)?,
+            playthrough.path_from_root(id)?,
+            "Loading must keep every save in its place."
+        );
+    }
+    Ok(())
+}
+
+/// Playtime is added to a single save, and counted for its descendants.
+#[test]
+fn playtime_with_ancestors() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    playthrough.add_playtime(root, Duration::from_mins(1))?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    playthrough.add_playtime(child, Duration::from_secs(5))?;
+    playthrough.delete_save(root)?;
+
+    assert_eq!(
+        playthrough.get_save(child)?.get_playtime(),
+        Duration::from_secs(5),
+        "A fork must start without the playtime of its parent."
+    );
+    assert_eq!(
+        playthrough.playtime_with_ancestors(child)?,
+        Duration::from_secs(65),
+        "Deleted ancestors must still count."
+    );
+    assert!(
+        playthrough
+            .add_playtime(root, Duration::from_secs(1))
+            .is_err(),
+        "Deleted saves can't be played, so they can't get playtime."
+    );
+    Ok(())
+}
+
+/// Deleted saves can be restored until the trash is compacted.
+#[test]
+fn restore_deleted_save() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    playthrough.add_playtime(child, Duration::from_secs(5))?;
+    playthrough.delete_save(child)?;
+    assert!(
+        playthrough.get_save(child)?.is_deleted(),
+        "A deleted save must count as deleted while it is in the trash."
+    );
+    assert!(
+        playthrough.restore_save(root).is_err(),
+        "Restoring a save that wasn't deleted must fail."
+    );
+
+    playthrough.restore_save(child)?;
+    assert!(
+        matches!(*playthrough.get_save(child)?, SaveEntry::Active(_)),
+        "A restored save must be active."
+    );
+    assert_eq!(
+        playthrough.get_save(child)?.get_playtime(),
+        Duration::from_secs(5),
+        "A restored save must keep its playtime."
+    );
+    playthrough.play_save(child)?;
+    Ok(())
+}
+
+/// Compacting the trash only affects deleted saves whose retention period has expired, and makes
+/// them impossible to restore.
+#[test]
+fn compact_trash() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    playthrough.delete_save(child)?;
+
+    assert_eq!(
+        playthrough.compact_trash(Duration::from_hours(1)),
+        0,
+        "Saves deleted just now must not be compacted."
+    );
+    assert!(
+        matches!(*playthrough.get_save(child)?, SaveEntry::Trashed(_)),
+        "Saves that weren't compacted must stay in the trash."
+    );
+
+    assert_eq!(
+        playthrough.compact_trash(Duration::ZERO),
+        1,
+        "Only the deleted save must be compacted."
+    );
+    assert!(
+        matches!(*playthrough.get_save(child)?, SaveEntry::Deleted(_)),
+        "Compacted saves must only keep their metadata."
+    );
+    assert!(
+        playthrough.restore_save(child).is_err(),
+        "Compacted saves can't be restored."
+    );
+    assert!(
+        matches!(*playthrough.get_save(root)?, SaveEntry::Active(_)),
+        "Active saves must not be compacted."
+    );
+    Ok(())
+}
+
+/// Saves in the trash survive being stored and loaded again.
+#[test]
+fn trash_survives_round_trip() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    playthrough.delete_save(child)?;
+    let mut loaded: Playthrough = Playthrough::from_bytes_migrated(&playthrough.to_bytes()?)?.0;
+    loaded.restore_save(child)?;
+    Ok(())
+}
+
+/// Pruning removes branches in which every save was deleted and compacted, but keeps deleted
+/// saves that still lead to a live save.
+#[test]
+fn prune_deleted_branches() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let kept: SaveId = playthrough.fork_save(root)?;
+    let kept_child: SaveId = playthrough.fork_save(kept)?;
+    let pruned: SaveId = playthrough.fork_save(root)?;
+    let pruned_child: SaveId = playthrough.fork_save(pruned)?;
+    let trashed: SaveId = playthrough.fork_save(root)?;
+    playthrough.add_playtime(pruned, Duration::from_secs(3))?;
+    playthrough.add_playtime(pruned_child, Duration::from_secs(4))?;
+    for id in [kept, pruned, pruned_child] {
+        playthrough.delete_save(id)?;
+    }
+    let _: usize = playthrough.compact_trash(Duration::ZERO);
+    playthrough.delete_save(trashed)?;
+
+    let report: PruneReport = playthrough.prune();
+    assert_eq!(
+        report.removed, 2,
+        "Only the fully deleted branch must be removed."
+    );
+    assert_eq!(
+        report.dropped_playtime,
+        Duration::from_secs(7),
+        "The playtime of every removed save must be reported."
+    );
+    assert_eq!(
+        playthrough.children(root)?,
+        vec![kept, trashed],
+        "Deleted saves with a live descendant, and restorable saves, must be kept."
+    );
+    assert!(
+        playthrough.get_save(pruned).is_err() && playthrough.get_save(pruned_child).is_err(),
+        "Removed saves must no longer be reachable."
+    );
+    assert_eq!(
+        playthrough.path_from_root(kept_child)?,
+        vec![root, kept, kept_child],
+        "The path to a live save must be kept."
+    );
+    assert_root_invariant(&playthrough)?;
+
+    let mut loaded: Playthrough = Playthrough::from_bytes_migrated(&playthrough.to_bytes()?)?.0;
+    assert_root_invariant(&loaded)?;
+    assert_eq!(
+        loaded.prune().removed,
+        0,
+        "Pruning twice must remove nothing."
+    );
+    Ok(())
+}
+
+/// The root save is never pruned, even if it and every one of its descendants was deleted.
+#[test]
+fn prune_keeps_root() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    playthrough.delete_save(root)?;
+    playthrough.delete_save(child)?;
+    let _: usize = playthrough.compact_trash(Duration::ZERO);
+
+    assert_eq!(
+        playthrough.prune().removed,
+        1,
+        "Only the child must be removed."
+    );
+    assert!(
+        playthrough.get_save(root).is_ok(),
+        "The root save must be kept."
+    );
+    assert_root_invariant(&playthrough)?;
+    Ok(())
+}
+
+/// Archives get fresh `SaveId`s, numbered in pre-order without gaps, and keep the tree intact.
+#[test]
+fn archive_round_trip() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let pruned: SaveId = playthrough.fork_save(root)?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    let grandchild: SaveId = playthrough.fork_save(child)?;
+    playthrough.add_playtime(grandchild, Duration::from_secs(9))?;
+    playthrough.delete_save(pruned)?;
+    let _: usize = playthrough.compact_trash(Duration::ZERO);
+    let _: PruneReport = playthrough.prune();
+
+    let imported: Playthrough = Playthrough::from_archive(&playthrough.to_archive(None)?)?;
+    assert_root_invariant(&imported)?;
+    let ids: Vec<usize> = imported
+        .path_from_root(imported.save_id_at(3)?)?
+        .into_iter()
+        .map(|id: SaveId| (*id).into())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![1, 2, 3],
+        "Imported saves must be renumbered without gaps."
+    );
+    assert_eq!(
+        imported.playtime_with_ancestors(imported.save_id_at(3)?)?,
+        Duration::from_secs(9),
+        "Imported saves must keep their playtime."
+    );
+    Ok(())
+}
+
+/// Exporting a branch only keeps the path from the root save to the chosen save.
+#[test]
+fn archive_branch() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let _: SaveId = playthrough.fork_save(root)?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    let grandchild: SaveId = playthrough.fork_save(child)?;
+    let _: SaveId = playthrough.fork_save(child)?;
+
+    let mut imported: Playthrough = Playthrough::from_archive(&playthrough.to_archive(Some(grandchild))?)?;
+    assert_root_invariant(&imported)?;
+    let imported_root: SaveId = imported.save_id_at(1)?;
+    assert_eq!(
+        imported.descendants(imported_root)?.len(),
+        2,
+        "Only the saves on the branch must be exported."
+    );
+    assert!(
+        imported.play_save(imported.save_id_at(3)?).is_ok(),
+        "The chosen save must stay playable."
+    );
+    Ok(())
+}
+
+/// Archives from another format version, and files that aren't archives, are refused.
+#[test]
+fn archive_version_mismatch() -> Result<()> {
+    let (playthrough, _): (Playthrough, SaveId) = with_root()?;
+    let archive: Vec<u8> = playthrough.to_archive(None)?;
+    let Some(newline) = archive.iter().position(|byte: &u8| *byte == b'\n') else {
+        panic!("Archives must have a header line.");
+    };
+    let mut future_archive: Vec<u8> = format!(
+        "spalst-archive {}",
+        Playthrough::FORMAT_VERSION.saturating_add(1)
+    )
+    .into_bytes();
+    future_archive.extend(archive.iter().skip(newline));
+
+    assert!(
+        Playthrough::from_archive(&future_archive).is_err(),
+        "Archives from another format version must be refused."
+    );
+    assert!(
+        Playthrough::from_archive(&playthrough.to_bytes()?).is_err(),
+        "Stored playthroughs aren't archives."
+    );
+    Ok(())
+}
+
+/// Every format version before the current one can be upgraded from.
+#[test]
+fn migrations_cover_every_version() {
+    for version in 1..Playthrough::FORMAT_VERSION {
+        assert!(
+            Playthrough::MIGRATIONS
+                .iter()
+                .any(|migration: &Migration| migration.from == version),
+            "Format version {version} must have a migration."
+        );
+    }
+}
+
+/// A file from format version 1, which didn't store whether saves were forked, is upgraded on
+/// load and reports what changed.
+#[test]
+fn migrate_from_version_1() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_save(root)?;
+    let mut body: Value = serde_json::to_value(playthrough.to_stored()?)?;
+    let Some(saves) = body.get_mut("saves").and_then(Value::as_array_mut) else {
+        panic!("Stored playthroughs must have a list of saves.");
+    };
+    for stored_save in saves {
+        if let Some(save) = stored_save
+            .pointer_mut("/entry/Active")
+            .and_then(Value::as_object_mut)
+        {
+            let _: Option<Value> = save.remove("sealed");
+        }
+    }
+    let mut bytes: Vec<u8> = b"spalst-playthrough 1\n".to_vec();
+    serde_json::to_writer(&mut bytes, &body)?;
+
+    let (migrated, report): (Playthrough, MigrationReport) = Playthrough::from_bytes_migrated(&bytes)?;
+    assert!(report.is_needed(), "Version 1 files must be upgraded.");
+    assert_eq!(report.from, 1, "The report must start at version 1.");
+    assert_eq!(
+        report.applied.len(),
+        Playthrough::MIGRATIONS.len(),
+        "Every migration must be applied."
+    );
+    assert_eq!(report.changes.len(), 1, "Only the root save was forked.");
+    assert!(migrated.is_sealed(root)?, "Forked saves must be sealed.");
+    assert!(
+        !migrated.is_sealed(child)?,
+        "Unforked saves must not be sealed."
+    );
+
+    let (_, current): (Playthrough, MigrationReport) = Playthrough::from_bytes_migrated(&migrated.to_bytes()?)?;
+    assert!(
+        !current.is_needed() && current.changes.is_empty(),
+        "Files in the current format must not be upgraded."
+    );
+    Ok(())
+}
+
+/// Files from a newer format version, or from a version no migration upgrades from, are refused.
+#[test]
+fn migrate_unsupported_versions() {
+    assert!(
+        Playthrough::migrate(
+            &mut Value::Null,
+            Playthrough::FORMAT_VERSION.saturating_add(1),
+            Playthrough::MIGRATIONS
+        )
+        .is_err(),
+        "Newer format versions must be refused."
+    );
+    assert!(
+        Playthrough::migrate(&mut Value::Null, 0, Playthrough::MIGRATIONS).is_err(),
+        "Versions without a migration must be refused."
+    );
+    assert!(
+        Playthrough::migrate(&mut Value::Null, 1, &[]).is_err(),
+        "Gaps in the chain of migrations must be refused."
+    );
+}
+
+/// Make a `Playthrough` whose root save was forked twice, and store it. Returns the stored form
+/// along with the indexes of the root and both forks.
+fn stored_with_forks() -> Result<(StoredPlaythrough, [usize; 3])> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let first: SaveId = playthrough.fork_save(root)?;
+    let second: SaveId = playthrough.fork_save(root)?;
+    Ok((
+        playthrough.to_stored()?,
+        [(*root).into(), (*first).into(), (*second).into()],
+    ))
+}
+
+/// Get the stored save with this index.
+fn stored_save(
+    stored: &mut StoredPlaythrough,
+    index: usize,
+) -> &mut StoredSave {
+    let Some(save) = stored
+        .saves
+        .iter_mut()
+        .find(|save: &&mut StoredSave| save.id == index)
+    else {
+        panic!("Save {index} must be stored.");
+    };
+    save
+}
+
+/// Intact playthroughs have no problems.
+#[test]
+fn verify_intact() -> Result<()> {
+    let (stored, _): (StoredPlaythrough, [usize; 3]) = stored_with_forks()?;
+    let bytes: Vec<u8> = Playthrough::with_header(Playthrough::FORMAT_MAGIC, &stored)?;
+    assert!(
+        Playthrough::verify(&bytes)?.is_clean(),
+        "Intact playthroughs must have no problems."
+    );
+    Ok(())
+}
+
+/// Every kind of problem is found, and the ones that can't be repaired are left alone.
+#[test]
+fn verify_finds_problems() -> Result<()> {
+    let (mut stored, [root, first, second]): (StoredPlaythrough, [usize; 3]) = stored_with_forks()?;
+    stored.root_save = Some(42);
+    stored_save(&mut stored, second).parent = Some(99);
+    stored_save(&mut stored, first).checksum ^= 1;
+    stored_save(&mut stored, root).parent = Some(first);
+    let bytes: Vec<u8> = Playthrough::with_header(Playthrough::FORMAT_MAGIC, &stored)?;
+    let problems: Vec<IntegrityProblem> = Playthrough::verify(&bytes)?.problems;
+
+    for expected in [
+        IntegrityProblem::BadRoot(42),
+        IntegrityProblem::DanglingParent {
+            save: second,
+            parent: 99,
+        },
+        IntegrityProblem::ChecksumMismatch(first),
+        IntegrityProblem::Cycle(vec![root, first]),
+        IntegrityProblem::BornBeforeParent {
+            save: root,
+            parent: first,
+        },
+    ] {
+        assert!(
+            problems.contains(&expected),
+            "\"{expected}\" must be found."
+        );
+    }
+    assert!(
+        Playthrough::from_bytes_migrated(&bytes).is_err(),
+        "Playthroughs with these problems must not load."
+    );
+    assert!(
+        Playthrough::repair(&bytes).is_err(),
+        "Checksum mismatches can't be repaired."
+    );
+    Ok(())
+}
+
+/// Saves with absurdly high indexes are refused before any room is made for them.
+#[test]
+fn refuse_huge_save_index() -> Result<()> {
+    for index in [Playthrough::MAX_SAVE_INDEX.saturating_add(1), usize::MAX] {
+        let (mut stored, [_, _, second]): (StoredPlaythrough, [usize; 3]) = stored_with_forks()?;
+        stored_save(&mut stored, second).id = index;
+        let bytes: Vec<u8> = Playthrough::with_header(Playthrough::FORMAT_MAGIC, &stored)?;
+        assert!(
+            Playthrough::from_bytes_migrated(&bytes).is_err(),
+            "The index {index} must be refused."
+        );
+    }
+    Ok(())
+}
+
+/// Cut off saves are attached to the root save again, and a missing root save is replaced by the
+/// only save without a parent.
+#[test]
+fn repair_reattaches_saves() -> Result<()> {
+    let (mut stored, [root, first, second]): (StoredPlaythrough, [usize; 3]) = stored_with_forks()?;
+    stored.root_save = Some(42);
+    stored_save(&mut stored, second).parent = Some(99);
+    let bytes: Vec<u8> = Playthrough::with_header(Playthrough::FORMAT_MAGIC, &stored)?;
+
+    let (repaired, left): (Playthrough, IntegrityReport) = Playthrough::repair(&bytes)?;
+    assert!(left.is_clean(), "Every problem must be repaired.");
+    assert_root_invariant(&repaired)?;
+    assert_eq!(
+        repaired.root_save.map(|id: SaveId| usize::from(*id)),
+        Some(root),
+        "The only save without a parent must become the root save."
+    );
+    for index in [first, second] {
+        assert!(
+            repaired.get_save(repaired.save_id_at(index)?).is_ok(),
+            "No save may be lost."
+        );
+    }
+    assert!(
+        Playthrough::verify(&repaired.to_bytes()?)?.is_clean(),
+        "Repaired playthroughs must have no problems."
+    );
+    Ok(())
+}
+
+/// Autosaves fork the played save, and only the newest ones are kept, without losing playtime.
+#[test]
+fn autosave_rotation() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    playthrough.play_save(root)?;
+    let mut active: SaveId = root;
+    let mut autosaves: Vec<SaveId> = Vec::new();
+    for _ in 0_u8..4 {
+        playthrough.add_playtime(active, Duration::from_secs(10))?;
+        active = playthrough.autosave(active, 2)?;
+        autosaves.push(active);
+    }
+
+    assert!(
+        playthrough.get_save(active)?.is_autosave() && !playthrough.is_sealed(active)?,
+        "The fork made by an autosave must be marked and playable."
+    );
+    assert!(
+        !playthrough.get_save(root)?.is_autosave() && playthrough.is_sealed(root)?,
+        "The autosaved save must be sealed, but not marked."
+    );
+    assert_eq!(
+        playthrough.ancestors(active)?,
+        autosaves
+            .get(1..3)
+            .unwrap_or_default()
+            .iter()
+            .rev()
+            .copied()
+            .chain([root])
+            .collect::<Vec<SaveId>>(),
+        "Only the newest 2 autosaves above the active save must be kept."
+    );
+    assert_eq!(
+        playthrough.playtime_with_ancestors(active)?,
+        Duration::from_secs(40),
+        "Merging old autosaves must not lose playtime."
+    );
+    assert_root_invariant(&playthrough)?;
+    Ok(())
+}
+
+#[test]
+fn search_by_metadata() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let boss: SaveId = playthrough.fork_save(root)?;
+    let other: SaveId = playthrough.fork_save(root)?;
+    playthrough.set_metadata(
+        boss,
+        SaveMetadata {
+            name: "Before the boss".to_owned(),
+            notes: "Full health, no potions.".to_owned(),
+            tags: vec![ColorTag::Red],
+            pinned: true,
+        },
+    )?;
+    playthrough.set_metadata(
+        other,
+        SaveMetadata {
+            tags: vec![ColorTag::Red, ColorTag::Blue],
+            ..SaveMetadata::default()
+        },
+    )?;
+    playthrough.delete_save(boss)?;
+
+    let search = |text: &str, tags: Vec<ColorTag>, pinned_only: bool| -> Vec<SaveId> {
+        playthrough.search(&SaveFilter {
+            text: text.to_owned(),
+            tags,
+            pinned_only,
+        })
+    };
+    assert_eq!(
+        search("POTIONS", Vec::new(), false),
+        vec![boss],
+        "Searching must match the notes of deleted saves, ignoring case."
+    );
+    assert_eq!(
+        search("", vec![ColorTag::Red], false),
+        vec![boss, other],
+        "Searching by a tag must match every save with it."
+    );
+    assert_eq!(
+        search("", vec![ColorTag::Red, ColorTag::Blue], false),
+        vec![other],
+        "Searching by tags must match the saves with all of them."
+    );
+    assert_eq!(
+        search("", Vec::new(), true),
+        vec![boss],
+        "Searching for pinned saves must match only those."
+    );
+
+    let loaded: Playthrough = Playthrough::from_bytes_migrated(&playthrough.to_bytes()?)?.0;
+    assert_eq!(
+        loaded.get_save(boss)?.metadata().name,
+        "Before the boss",
+        "The metadata of a deleted save must be stored."
+    );
+    Ok(())
+}
+
+#[test]
+fn common_ancestor() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let first: SaveId = playthrough.fork_save(root)?;
+    let second: SaveId = playthrough.fork_save(root)?;
+    let grandchild: SaveId = playthrough.fork_save(first)?;
+    assert_eq!(
+        playthrough.common_ancestor(grandchild, second)?,
+        Some(root),
+        "Saves in different branches must have the save they branched from in common."
+    );
+    assert_eq!(
+        playthrough.common_ancestor(first, grandchild)?,
+        Some(first),
+        "A save must be the common ancestor of itself and its descendants."
+    );
+    assert_eq!(
+        playthrough.common_ancestor(second, second)?,
+        Some(second),
+        "A save must be its own common ancestor."
+    );
+    Ok(())
+}
+
+#[test]
+fn diff_saves() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let fork: SaveId = playthrough.fork_save(root)?;
+    playthrough.add_playtime(fork, Duration::from_secs(5))?;
+    playthrough.set_metadata(
+        fork,
+        SaveMetadata {
+            tags: vec![ColorTag::Green],
+            ..SaveMetadata::default()
+        },
+    )?;
+
+    let diff: SaveDiff = playthrough.diff(root, fork)?;
+    assert_eq!(
+        diff.common_ancestor,
+        Some(root),
+        "The diff must know how the saves are related."
+    );
+    assert!(
+        diff.changes.contains(&SaveChange::Added {
+            path: "/metadata/tags/0".to_owned(),
+            value: Value::from("Green"),
+        }),
+        "A value only the newer save has must be added."
+    );
+    assert!(
+        diff.changes.contains(&SaveChange::Changed {
+            path: "/sealed".to_owned(),
+            before: Value::Bool(true),
+            after: Value::Bool(false),
+        }),
+        "A value both saves have must be changed."
+    );
+    assert!(
+        diff.changes
+            .iter()
+            .any(|change: &SaveChange| change.path() == "/playtime/secs"),
+        "Nested values must be compared one by one."
+    );
+    assert!(
+        playthrough.diff(fork, fork)?.is_empty(),
+        "A save must be the same as itself."
+    );
+
+    playthrough.delete_save(fork)?;
+    assert!(
+        playthrough.diff(root, fork).is_ok(),
+        "Saves in the trash must be comparable."
+    );
+    Ok(())
+}
+
+#[test]
+fn resume_falls_back_to_ancestor() -> Result<()> {
+    let (mut playthrough, root): (Playthrough, SaveId) = with_root()?;
+    let child: SaveId = playthrough.fork_and_play(root)?;
+    let grandchild: SaveId = playthrough.fork_and_play(child)?;
+    let path: Vec<SaveMark> = playthrough.index_path(grandchild)?;
+    assert_eq!(
+        playthrough.resume_point(&path),
+        Some(grandchild),
+        "A save that still exists must be resumed from."
+    );
+
+    playthrough.delete_save(grandchild)?;
+    assert_eq!(
+        playthrough.resume_point(&path),
+        Some(child),
+        "A deleted save must fall back to its nearest active ancestor."
+    );
+    let resumed: SaveId = playthrough.resume(&path)?;
+    assert_eq!(
+        playthrough.ancestors(resumed)?.first(),
+        Some(&child),
+        "Resuming from a sealed save must play a fork of it."
+    );
+
+    let _: usize = playthrough.compact_trash(Duration::ZERO);
+    let _: PruneReport = playthrough.prune();
+    assert!(
+        playthrough.resume_point(&path).is_some(),
+        "A removed save must fall back to the part of its path that still exists."
+    );
+    let _: SaveId = playthrough.fork_save(child)?;
+    assert_eq!(
+        playthrough.resume_point(&path),
+        Some(child),
+        "A save that took the index of a removed one must not be resumed from."
+    );
+    assert_eq!(
+        playthrough.resume_point(&[]),
+        None,
+        "An empty path must not lead to any save."
+    );
+    Ok(())
+}
+
+#[test]
+fn settings_survive_round_trip() -> Result<()> {
+    let (mut playthrough, _): (Playthrough, SaveId) = with_root()?;
+    let settings: PlaythroughSettings = PlaythroughSettings {
+        difficulty: Difficulty::Hard,
+        permadeath: true,
+        seed: 42,
+    };
+    playthrough.set_settings(settings);
+    assert_eq!(
+        Playthrough::from_bytes_migrated(&playthrough.to_bytes()?)?
+            .0
+            .settings(),
+        settings,
+        "The settings must be stored."
+    );
+    assert_eq!(
+        Playthrough::from_archive(&playthrough.to_archive(None)?)?.settings(),
+        settings,
+        "The settings must be exported."
+    );
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    debug,
+    enums::SaveEntry,
+    structs::{DeletedSave, Playthrough, SaveId, TrashedSave},
+};
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::Result;
+use core::time::Duration;
+use indextree::Node;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Restore the deleted save this `SaveId` points to, turning it back into a
+    /// `SaveEntry::Active`.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    /// If this `SaveId` points to a save that wasn't deleted.
+    /// If this `SaveId` points to a deleted save that was already compacted, so its game data is
+    /// gone.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn restore_save(
+        &mut self,
+        id: SaveId,
+    ) -> Result<()> {
+        let entry: &mut SaveEntry = self.get_save_mut(id)?;
+        let trashed_save: &TrashedSave = match *entry {
+            SaveEntry::Trashed(ref trashed_save) => trashed_save,
+            SaveEntry::Active(_) => bail_log!(
+                "Passed ID (with value {}) points to a save that wasn't deleted.",
+                *id
+            ),
+            SaveEntry::Deleted(_) => bail_log!(
+                "Passed ID (with value {}) points to a deleted save that can no longer be restored.",
+                *id
+            ),
+        };
+        *entry = SaveEntry::Active(trashed_save.save().clone());
+        Ok(())
+    }
+
+    /// Compact every `SaveEntry::Trashed` whose retention period has expired into a
+    /// `SaveEntry::Deleted`, throwing its game data away. Returns how many saves were compacted.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn compact_trash(
+        &mut self,
+        retention: Duration,
+    ) -> usize {
+        let now: DateTime<Utc> = Utc::now();
+        let mut compacted: usize = 0;
+        for node in self
+            .arena
+            .iter_mut()
+            .filter(|node: &&mut Node<SaveEntry>| !node.is_removed())
+        {
+            let entry: &mut SaveEntry = node.get_mut();
+            if let SaveEntry::Trashed(ref trashed_save) = *entry
+                && trashed_save.is_expired(retention, now)
+            {
+                *entry = SaveEntry::Deleted(DeletedSave::from(trashed_save.save()));
+                compacted = compacted.saturating_add(1);
+            }
+        }
+        if compacted > 0 {
+            debug!(
+                "Compacted {compacted} expired deleted saves of playthrough \"{}\".",
+                self.name
+            );
+        }
+        compacted
+    }
+}
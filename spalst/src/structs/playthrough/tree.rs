@@ -0,0 +1,125 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::SaveEntry,
+    structs::{Playthrough, SaveId},
+};
+use color_eyre::eyre::Result;
+use indextree::{Node, NodeId};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Get the `SaveId`s of every save without a parent, in storage order.
+    ///
+    /// As long as the tree is intact, this is just the root save.
+    pub fn tree_roots(&self) -> Vec<SaveId> {
+        self.arena
+            .iter_node_ids()
+            .filter(|id: &NodeId| {
+                let node: &Node<SaveEntry> = &self.arena[*id];
+                !node.is_removed() && node.parent().is_none()
+            })
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Get the `SaveId`s of the children of the save this `SaveId` points to, from the oldest
+    /// fork to the newest.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn children(
+        &self,
+        id: SaveId,
+    ) -> Result<Vec<SaveId>> {
+        let _: &Node<SaveEntry> = self.get_save_node(id)?;
+        Ok(id.children(&self.arena).map(Into::into).collect())
+    }
+
+    /// Get the `SaveId`s of the ancestors of the save this `SaveId` points to, from its parent to
+    /// the root of its tree.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn ancestors(
+        &self,
+        id: SaveId,
+    ) -> Result<Vec<SaveId>> {
+        let _: &Node<SaveEntry> = self.get_save_node(id)?;
+        Ok(id.ancestors(&self.arena).skip(1).map(Into::into).collect())
+    }
+
+    /// Get the `SaveId`s of every descendant of the save this `SaveId` points to, in pre-order
+    /// (every save comes before its children, and older forks come before newer ones).
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg(test)]
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn descendants(
+        &self,
+        id: SaveId,
+    ) -> Result<Vec<SaveId>> {
+        let _: &Node<SaveEntry> = self.get_save_node(id)?;
+        Ok(id
+            .descendants(&self.arena)
+            .skip(1)
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Get the depth of the save this `SaveId` points to, which is the number of its ancestors.
+    /// The root save has a depth of 0.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg(test)]
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn depth(
+        &self,
+        id: SaveId,
+    ) -> Result<usize> {
+        let _: &Node<SaveEntry> = self.get_save_node(id)?;
+        Ok(id.ancestors(&self.arena).skip(1).count())
+    }
+
+    /// Get the `SaveId`s of the path from the root of its tree to the save this `SaveId` points
+    /// to. Both ends are included.
+    ///
+    /// # Errors
+    /// If this `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn path_from_root(
+        &self,
+        id: SaveId,
+    ) -> Result<Vec<SaveId>> {
+        let _: &Node<SaveEntry> = self.get_save_node(id)?;
+        let mut path: Vec<SaveId> = id.ancestors(&self.arena).map(Into::into).collect();
+        path.reverse();
+        Ok(path)
+    }
+
+    /// Get the `SaveId` of the newest save that both of these `SaveId`s descend from. A save counts
+    /// as descending from itself, so if one of them is an ancestor of the other, it is returned.
+    /// `None` if they are in different trees.
+    ///
+    /// # Errors
+    /// If either `SaveId` doesn't point to a save.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn common_ancestor(
+        &self,
+        first: SaveId,
+        second: SaveId,
+    ) -> Result<Option<SaveId>> {
+        let second_path: Vec<SaveId> = self.path_from_root(second)?;
+        Ok(self
+            .path_from_root(first)?
+            .into_iter()
+            .rev()
+            .find(|id: &SaveId| second_path.contains(id)))
+    }
+}
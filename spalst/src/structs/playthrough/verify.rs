@@ -0,0 +1,204 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::IntegrityProblem,
+    structs::{IntegrityReport, MigrationReport, Playthrough, StoredPlaythrough, StoredSave},
+};
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::Result;
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl Playthrough {
+    /// Check a `Playthrough` serialized with `Playthrough::to_bytes()` for structural problems,
+    /// without loading it.
+    ///
+    /// Loading refuses most of these problems, so this is the way to find out what is wrong with a
+    /// file that doesn't load.
+    ///
+    /// # Errors
+    /// If the bytes can't be read as a `StoredPlaythrough` at all (see `Playthrough::from_bytes_migrated()`).
+    /// If computing the checksum of a save fails.
+    #[cfg_attr(feature = "logging", instrument(skip(bytes)))]
+    pub fn verify(bytes: &[u8]) -> Result<IntegrityReport> {
+        let (stored, _): (StoredPlaythrough, MigrationReport) = Self::without_header(bytes, Self::FORMAT_MAGIC)?;
+        Self::check_stored(&stored)
+    }
+
+    /// Repair what can be repaired safely in a `Playthrough` serialized with
+    /// `Playthrough::to_bytes()`, and load it.
+    ///
+    /// Saves that were cut off from the tree (because their parent is missing, or they are part of
+    /// a cycle) are attached to the root save. If the root save is missing, the only save without a
+    /// parent becomes the root save. No save is ever removed. Returns the repaired `Playthrough`,
+    /// along with the problems that are left (see `IntegrityProblem::is_repairable()`).
+    ///
+    /// # Errors
+    /// If the bytes can't be read as a `StoredPlaythrough` at all (see `Playthrough::from_bytes_migrated()`).
+    /// If computing the checksum of a save fails.
+    /// If the problems that are left keep the `Playthrough` from loading (see
+    /// `Playthrough::from_stored()`).
+    #[cfg_attr(feature = "logging", instrument(skip(bytes)))]
+    pub fn repair(bytes: &[u8]) -> Result<(Self, IntegrityReport)> {
+        let (mut stored, _): (StoredPlaythrough, MigrationReport) = Self::without_header(bytes, Self::FORMAT_MAGIC)?;
+        Self::repair_stored(&mut stored);
+        let report: IntegrityReport = Self::check_stored(&stored)?;
+        Ok((Self::from_stored(stored)?, report))
+    }
+
+    /// Find every structural problem in a `StoredPlaythrough`.
+    ///
+    /// # Errors
+    /// If computing the checksum of a save fails.
+    fn check_stored(stored: &StoredPlaythrough) -> Result<IntegrityReport> {
+        let mut report: IntegrityReport = IntegrityReport::default();
+        let mut birth_times: HashMap<usize, DateTime<Utc>> = HashMap::with_capacity(stored.saves.len());
+        for save in &stored.saves {
+            if save.id == 0
+                || birth_times
+                    .insert(save.id, save.entry.get_birth_time())
+                    .is_some()
+            {
+                report
+                    .problems
+                    .push(IntegrityProblem::InvalidIndex(save.id));
+            }
+            if !save.has_valid_checksum()? {
+                report
+                    .problems
+                    .push(IntegrityProblem::ChecksumMismatch(save.id));
+            }
+        }
+
+        let roots: Vec<usize> = Self::stored_roots(stored);
+        if roots.len() > 1 {
+            report.problems.push(IntegrityProblem::MultipleRoots(roots));
+        }
+        if let Some(root) = stored.root_save
+            && !birth_times.contains_key(&root)
+        {
+            report.problems.push(IntegrityProblem::BadRoot(root));
+        }
+
+        for save in &stored.saves {
+            let Some(parent) = save.parent else {
+                continue;
+            };
+            match birth_times.get(&parent) {
+                None => report.problems.push(IntegrityProblem::DanglingParent {
+                    save: save.id,
+                    parent,
+                }),
+                Some(parent_birth_time) if save.entry.get_birth_time() < *parent_birth_time => {
+                    report.problems.push(IntegrityProblem::BornBeforeParent {
+                        save: save.id,
+                        parent,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        report.problems.extend(
+            Self::stored_cycles(stored)
+                .into_iter()
+                .map(IntegrityProblem::Cycle),
+        );
+        Ok(report)
+    }
+
+    /// Repair the problems of a `StoredPlaythrough` that can be repaired without losing saves (see
+    /// `Playthrough::repair()`).
+    fn repair_stored(stored: &mut StoredPlaythrough) {
+        let ids: HashSet<usize> = stored
+            .saves
+            .iter()
+            .map(|save: &StoredSave| save.id)
+            .collect();
+        // Saves that had no parent to begin with, as opposed to the ones cut off below.
+        let roots: Vec<usize> = Self::stored_roots(stored);
+        for save in &mut stored.saves {
+            if save
+                .parent
+                .is_some_and(|parent: usize| !ids.contains(&parent))
+            {
+                save.parent = None;
+            }
+        }
+        for cycle in Self::stored_cycles(stored) {
+            if let Some(first) = cycle.first() {
+                for save in stored
+                    .saves
+                    .iter_mut()
+                    .filter(|save: &&mut StoredSave| save.id == *first)
+                {
+                    save.parent = None;
+                }
+            }
+        }
+
+        if stored
+            .root_save
+            .is_some_and(|root: usize| !ids.contains(&root))
+        {
+            stored.root_save = match *roots.as_slice() {
+                [root] => Some(root),
+                [..] => stored.root_save,
+            };
+        }
+        // Attaching the other saves to a root save that has a parent could make a new cycle.
+        if let Some(root) = stored.root_save
+            && Self::stored_roots(stored).contains(&root)
+        {
+            for save in &mut stored.saves {
+                if save.parent.is_none() && save.id != root {
+                    save.parent = Some(root);
+                }
+            }
+        }
+    }
+
+    /// Get the indexes of the stored saves that have no parent, in the order they are stored in.
+    fn stored_roots(stored: &StoredPlaythrough) -> Vec<usize> {
+        stored
+            .saves
+            .iter()
+            .filter(|save: &&StoredSave| save.parent.is_none())
+            .map(|save: &StoredSave| save.id)
+            .collect()
+    }
+
+    /// Find every cycle formed by the parents of the stored saves. Every cycle is sorted, so the
+    /// save with the lowest index comes first.
+    fn stored_cycles(stored: &StoredPlaythrough) -> Vec<Vec<usize>> {
+        let parents: HashMap<usize, usize> = stored
+            .saves
+            .iter()
+            .filter_map(|save: &StoredSave| Some((save.id, save.parent?)))
+            .collect();
+        let mut visited: HashSet<usize> = HashSet::with_capacity(stored.saves.len());
+        let mut cycles: Vec<Vec<usize>> = Vec::new();
+
+        for save in &stored.saves {
+            let mut path: Vec<usize> = Vec::new();
+            let mut current: Option<usize> = Some(save.id);
+            while let Some(id) = current {
+                if let Some(position) = path.iter().position(|on_path: &usize| *on_path == id) {
+                    let mut cycle: Vec<usize> = path.get(position..).unwrap_or_default().to_vec();
+                    cycle.sort_unstable();
+                    cycles.push(cycle);
+                    break;
+                }
+                if visited.contains(&id) {
+                    break;
+                }
+                path.push(id);
+                current = parents.get(&id).copied();
+            }
+            visited.extend(path);
+        }
+        cycles
+    }
+}
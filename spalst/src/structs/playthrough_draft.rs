@@ -0,0 +1,42 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::enums::{Difficulty, WizardStep};
+use core::{hash::BuildHasher as _, num::ParseIntError};
+use std::collections::hash_map::RandomState;
+
+/// What the user entered into a `NewPlaythroughScreen` so far.
+#[derive(Debug, Default)]
+pub struct PlaythroughDraft {
+    /// The step the user is on.
+    pub step: WizardStep,
+    /// The name of the new `Playthrough`.
+    pub name: String,
+    /// How hard the game will be.
+    pub difficulty: Difficulty,
+    /// Whether dying will end the branch being played.
+    pub permadeath: bool,
+    /// The world seed, as entered. Empty for a random one.
+    pub seed: String,
+}
+
+impl PlaythroughDraft {
+    /// Whether the user entered anything yet.
+    pub fn is_started(&self) -> bool {
+        self.step != WizardStep::default() || !self.name.is_empty()
+    }
+
+    /// Describe what makes the entered seed unusable, if anything.
+    pub fn seed_problem(&self) -> Option<String> {
+        (!self.seed.is_empty() && self.seed.trim().parse::<u64>().is_err()).then(|| format!("The seed must be a whole number from 0 to {}.", u64::MAX))
+    }
+
+    /// Get the world seed to create the `Playthrough` with. If none was entered (or it isn't
+    /// valid), a random one is picked.
+    pub fn seed(&self) -> u64 {
+        self.seed
+            .trim()
+            .parse()
+            .unwrap_or_else(|_: ParseIntError| RandomState::new().hash_one(self.name.as_str()))
+    }
+}
@@ -0,0 +1,76 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::SaveMark;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Remembers which save of every stored `Playthrough` was played last, and which `Playthrough` was
+/// played last overall, so that "Continue" can jump straight back into it.
+///
+/// Saves are remembered by the `SaveMark`s of the path from the root save to them (see
+/// `Playthrough::index_path()`), so that a deleted save can fall back to its nearest active
+/// ancestor (see `Playthrough::resume_point()`). Stored next to the `Playthrough`s, see
+/// `PlaythroughStore::save_registry()`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PlaythroughRegistry {
+    /// The name of the `Playthrough` that was played last, if any.
+    last_played: Option<String>,
+    /// The path to the save that was played last, for every `Playthrough` that was played.
+    slots: BTreeMap<String, Vec<SaveMark>>,
+}
+
+impl PlaythroughRegistry {
+    /// Get the name of the `Playthrough` that was played last, if any.
+    pub fn last_played(&self) -> Option<&str> {
+        self.last_played.as_deref()
+    }
+
+    /// Get the path to the save of the `Playthrough` with this name that was played last, if any.
+    pub fn slot(
+        &self,
+        name: &str,
+    ) -> Option<&[SaveMark]> {
+        self.slots.get(name).map(Vec::as_slice)
+    }
+
+    /// Remember that the save at the end of this path was just played, in the `Playthrough` with
+    /// this name. Returns whether anything changed.
+    pub fn record(
+        &mut self,
+        name: &str,
+        path: Vec<SaveMark>,
+    ) -> bool {
+        if self.last_played() == Some(name) && self.slot(name) == Some(path.as_slice()) {
+            return false;
+        }
+        self.last_played = Some(name.to_owned());
+        let _: Option<Vec<SaveMark>> = self.slots.insert(name.to_owned(), path);
+        true
+    }
+
+    /// Keep remembering the `Playthrough` with this name under its new name.
+    pub fn rename(
+        &mut self,
+        name: &str,
+        new_name: &str,
+    ) {
+        if let Some(path) = self.slots.remove(name) {
+            let _: Option<Vec<SaveMark>> = self.slots.insert(new_name.to_owned(), path);
+        }
+        if self.last_played() == Some(name) {
+            self.last_played = Some(new_name.to_owned());
+        }
+    }
+
+    /// Forget the `Playthrough` with this name.
+    pub fn forget(
+        &mut self,
+        name: &str,
+    ) {
+        let _: Option<Vec<SaveMark>> = self.slots.remove(name);
+        if self.last_played() == Some(name) {
+            self.last_played = None;
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::enums::Difficulty;
+use serde::{Deserialize, Serialize};
+
+/// The rules a `Playthrough` was created with. They can't be changed afterwards.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PlaythroughSettings {
+    /// How hard the game is.
+    pub difficulty: Difficulty,
+    /// Whether dying ends the branch being played, instead of going back to the last save.
+    pub permadeath: bool,
+    /// The seed the world is generated from.
+    pub seed: u64,
+}
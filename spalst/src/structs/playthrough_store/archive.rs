@@ -0,0 +1,75 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    info,
+    structs::{Playthrough, PlaythroughStore, SaveId},
+};
+use color_eyre::eyre::{Result, WrapErr as _};
+use std::{fs, path::Path};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// The extension given to archives by default.
+    pub const ARCHIVE_EXTENSION: &str = "spalst";
+
+    /// Export the `Playthrough` with this name into an archive at this path (see
+    /// `Playthrough::to_archive()`). An existing file at this path is overwritten.
+    ///
+    /// If `save` is given, only the branch from the root save to the save with that index is
+    /// exported.
+    ///
+    /// # Errors
+    /// If loading the `Playthrough` fails.
+    /// If it has no save with the index `save`.
+    /// If serializing or writing the archive fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn export(
+        &self,
+        name: &str,
+        save: Option<usize>,
+        path: &Path,
+    ) -> Result<()> {
+        let playthrough: Playthrough = self.load(name)?;
+        let branch: Option<SaveId> = save
+            .map(|index: usize| playthrough.save_id_at(index))
+            .transpose()?;
+        fs::write(path, playthrough.to_archive(branch)?).wrap_err_with(|| format!("Failed to write the archive {}", path.display()))?;
+        info!("Exported playthrough \"{name}\" to {}.", path.display());
+        Ok(())
+    }
+
+    /// Import the `Playthrough` from the archive at this path (see `Playthrough::from_archive()`).
+    /// Returns the name it was stored under.
+    ///
+    /// It is stored under `name` if given, or under the name it had when it was exported
+    /// otherwise.
+    ///
+    /// # Errors
+    /// If reading the archive fails, or it isn't a valid archive.
+    /// If the name isn't valid, or is already taken.
+    /// If saving the `Playthrough` fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn import(
+        &self,
+        path: &Path,
+        name: Option<&str>,
+    ) -> Result<String> {
+        let mut playthrough: Playthrough = fs::read(path)
+            .map_err(Into::into)
+            .and_then(|bytes: Vec<u8>| Playthrough::from_archive(&bytes))
+            .wrap_err_with(|| format!("Failed to read the archive {}", path.display()))?;
+        if let Some(name) = name {
+            playthrough.set_name(name.to_owned());
+        }
+        self.ensure_free_name(playthrough.name())?;
+        self.save(&playthrough)?;
+        info!(
+            "Imported playthrough \"{}\" from {}.",
+            playthrough.name(),
+            path.display()
+        );
+        Ok(playthrough.name().to_owned())
+    }
+}
@@ -0,0 +1,82 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    bail_log,
+    info,
+    structs::{Playthrough, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+use std::fs;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Store a copy of the `Playthrough` with this name under a new name.
+    ///
+    /// Backups aren't copied.
+    ///
+    /// # Errors
+    /// If either name isn't valid.
+    /// If a `Playthrough` with the new name already exists.
+    /// If loading or saving fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn duplicate(
+        &self,
+        name: &str,
+        new_name: &str,
+    ) -> Result<()> {
+        self.ensure_free_name(new_name)?;
+        let mut playthrough: Playthrough = self.load(name)?;
+        playthrough.set_name(new_name.to_owned());
+        self.save(&playthrough)?;
+        info!("Duplicated playthrough \"{name}\" as \"{new_name}\".");
+        Ok(())
+    }
+
+    /// Rename the `Playthrough` with this name. Its backups are renamed along with it.
+    ///
+    /// # Errors
+    /// If either name isn't valid.
+    /// If a `Playthrough` with the new name already exists.
+    /// If loading, saving, renaming or deleting any of the files fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn rename(
+        &self,
+        name: &str,
+        new_name: &str,
+    ) -> Result<()> {
+        self.ensure_free_name(new_name)?;
+        let mut playthrough: Playthrough = self.load(name)?;
+        playthrough.set_name(new_name.to_owned());
+
+        // The old files are only touched once the new one is written, so a failed save leaves the
+        // `Playthrough` as it was.
+        self.save(&playthrough)?;
+        for number in self.backup_numbers(name)? {
+            fs::rename(
+                self.backup_path_of(name, number)?,
+                self.backup_path_of(new_name, number)?,
+            )?;
+        }
+        fs::remove_file(self.path_of(name)?)?;
+        self.sync_dir()?;
+
+        info!("Renamed playthrough \"{name}\" to \"{new_name}\".");
+        Ok(())
+    }
+
+    /// Ensure that no `Playthrough` with this name exists yet, and that the name is valid.
+    ///
+    /// # Errors
+    /// If it isn't valid, or is already taken.
+    pub fn ensure_free_name(
+        &self,
+        name: &str,
+    ) -> Result<()> {
+        if self.exists(name)? {
+            bail_log!("A playthrough named \"{name}\" already exists.");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{bail_log, structs::AbsolutePathBuf};
+use color_eyre::eyre::{Result, WrapErr as _};
+use core::time::Duration;
+use std::fs;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+/// Stores `Playthrough`s on disk, one file per `Playthrough`.
+///
+/// Files are named after the `Playthrough` they contain, with `PlaythroughStore::EXTENSION` as
+/// their extension. Their format is documented in `Playthrough::to_bytes()`.
+///
+/// Writes are atomic, and the previous versions of every file are kept as rotated backups
+/// (`<file>.bak.1` being the newest), see `PlaythroughStore::save()`.
+#[derive(Clone, Debug)]
+pub struct PlaythroughStore {
+    /// The directory containing the files.
+    pub(super) dir: AbsolutePathBuf,
+    /// How many backups to keep per `Playthrough`.
+    pub(super) backups: usize,
+    /// How long deleted saves can be restored for (see `TrashedSave`).
+    ///
+    /// Expired deleted saves are compacted whenever a `Playthrough` is loaded.
+    pub(super) trash_retention: Duration,
+}
+
+impl PlaythroughStore {
+    /// The extension of every file containing a `Playthrough`.
+    pub const EXTENSION: &str = "playthrough";
+    /// The maximum length of a `Playthrough` name, in characters.
+    pub const MAX_NAME_LEN: usize = 64;
+
+    /// Constructs a new `PlaythroughStore` instance.
+    ///
+    /// The directory doesn't have to exist yet, see `PlaythroughStore::ensure_dir()`.
+    pub const fn new(
+        dir: AbsolutePathBuf,
+        backups: usize,
+        trash_retention: Duration,
+    ) -> Self {
+        Self {
+            dir,
+            backups,
+            trash_retention,
+        }
+    }
+
+    /// Get how long deleted saves can be restored for.
+    pub const fn trash_retention(&self) -> Duration {
+        self.trash_retention
+    }
+
+    /// The path of the file containing the `Playthrough` with this name.
+    ///
+    /// # Errors
+    /// If the name isn't valid (see `PlaythroughStore::ensure_valid_name()`).
+    pub fn path_of(
+        &self,
+        name: &str,
+    ) -> Result<AbsolutePathBuf> {
+        Self::ensure_valid_name(name)?;
+        Ok(self.dir.join(format!("{name}.{}", Self::EXTENSION)))
+    }
+
+    /// Read the file of the `Playthrough` with this name.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If reading the file fails.
+    pub(super) fn read(
+        &self,
+        name: &str,
+    ) -> Result<Vec<u8>> {
+        let path: AbsolutePathBuf = self.path_of(name)?;
+        fs::read(&path).wrap_err_with(|| format!("Failed to read {}", path.display()))
+    }
+
+    /// The path of the `number`th backup of the `Playthrough` with this name. The newest backup
+    /// is number 1.
+    ///
+    /// # Errors
+    /// If the name isn't valid (see `PlaythroughStore::ensure_valid_name()`).
+    pub fn backup_path_of(
+        &self,
+        name: &str,
+        number: usize,
+    ) -> Result<AbsolutePathBuf> {
+        Self::ensure_valid_name(name)?;
+        Ok(self
+            .dir
+            .join(format!("{name}.{}.bak.{number}", Self::EXTENSION)))
+    }
+
+    /// The path of the temporary file that a new version of the `Playthrough` with this name is
+    /// written to, before it replaces the old one.
+    ///
+    /// Starts with a `.`, which valid names can't, so it can't collide with a real `Playthrough`.
+    ///
+    /// # Errors
+    /// If the name isn't valid (see `PlaythroughStore::ensure_valid_name()`).
+    pub(super) fn temp_path_of(
+        &self,
+        name: &str,
+    ) -> Result<AbsolutePathBuf> {
+        Self::ensure_valid_name(name)?;
+        Ok(self.dir.join(format!(".{name}.{}.tmp", Self::EXTENSION)))
+    }
+
+    /// Ensure that this name can be used for a `Playthrough`.
+    ///
+    /// Names are used as file names, so anything that could escape the directory or hide the file
+    /// is rejected.
+    ///
+    /// # Errors
+    /// If the name is empty, too long, has surrounding whitespace, starts with a `.`, or contains a
+    /// path separator or a control character.
+    #[cfg_attr(feature = "logging", instrument)]
+    pub fn ensure_valid_name(name: &str) -> Result<()> {
+        if let Some(problem) = Self::name_problem(name) {
+            bail_log!("{problem}");
+        }
+        Ok(())
+    }
+
+    /// Describe what makes this name invalid for a `Playthrough`, if anything.
+    ///
+    /// Same as `PlaythroughStore::ensure_valid_name()`, except nothing is logged. Meant for
+    /// validating names while they are being typed in.
+    pub fn name_problem(name: &str) -> Option<String> {
+        if name.is_empty() {
+            Some("Playthrough name can't be empty.".to_owned())
+        } else if name.chars().count() > Self::MAX_NAME_LEN {
+            Some(format!(
+                "Playthrough name can't be longer than {} characters.",
+                Self::MAX_NAME_LEN
+            ))
+        } else if name.trim() != name {
+            Some("Playthrough name can't start or end with whitespace.".to_owned())
+        } else if name.starts_with('.') {
+            Some("Playthrough name can't start with a \".\".".to_owned())
+        } else if name
+            .chars()
+            .any(|character: char| matches!(character, '/' | '\\') || character.is_control())
+        {
+            Some("Playthrough name can't contain slashes or control characters.".to_owned())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    info,
+    structs::{AbsolutePathBuf, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+use std::fs;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Delete the `Playthrough` with this name, along with all of its backups.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If deleting any of the files fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn delete(
+        &self,
+        name: &str,
+    ) -> Result<()> {
+        let path: AbsolutePathBuf = self.path_of(name)?;
+        if path.try_exists()? {
+            fs::remove_file(&path)?;
+        }
+        self.remove_backups_from(name, 1)?;
+        self.sync_dir()?;
+        info!("Deleted playthrough \"{name}\".");
+        Ok(())
+    }
+
+    /// Whether a `Playthrough` with this name is stored.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If checking whether the file exists fails.
+    pub fn exists(
+        &self,
+        name: &str,
+    ) -> Result<bool> {
+        Ok(self.path_of(name)?.try_exists()?)
+    }
+}
@@ -0,0 +1,30 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{bail_log, info, structs::PlaythroughStore};
+use color_eyre::eyre::Result;
+use std::fs::create_dir_all;
+
+impl PlaythroughStore {
+    /// Create the directory of this `PlaythroughStore` if it doesn't already exist.
+    ///
+    /// # Errors
+    /// If the directory already exists but is not a directory.
+    pub fn ensure_dir(&self) -> Result<()> {
+        if !self.dir.try_exists()? {
+            // If the path doesn't exist, create it.
+            create_dir_all(&self.dir)?;
+            info!("Created {}", self.dir.display());
+        } else if !self.dir.is_dir() {
+            // If the path does exist, but isn't a directory, bail.
+            bail_log!(
+                "{} exists but is not a directory. To avoid loss of data, it will not be deleted. Please move or delete it.",
+                self.dir.display()
+            );
+        } else {
+            // The path is a directory that exists. No need to do anything.
+        }
+
+        Ok(())
+    }
+}
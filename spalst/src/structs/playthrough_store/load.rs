@@ -0,0 +1,108 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    debug,
+    error,
+    info,
+    structs::{AbsolutePathBuf, MigrationReport, Playthrough, PlaythroughStore},
+    warn,
+};
+use color_eyre::eyre::{Report, Result, WrapErr as _};
+use std::{fs, path::Path};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Load the `Playthrough` with this name.
+    ///
+    /// If its file can't be loaded, its backups are tried from newest to oldest, and the first one
+    /// that loads is returned instead.
+    ///
+    /// Expired deleted saves are compacted, see `PlaythroughStore::prepared()`.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If neither the file nor any of its backups contain a valid `Playthrough`.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn load(
+        &self,
+        name: &str,
+    ) -> Result<Playthrough> {
+        let path: AbsolutePathBuf = self.path_of(name)?;
+        let report: Report = match Self::load_file(&path) {
+            Ok(playthrough) => {
+                debug!("Loaded playthrough \"{name}\" from {}", path.display());
+                return Ok(self.prepared(playthrough, name));
+            }
+            Err(report) => report,
+        };
+        error!("{report:#}");
+
+        for number in self.backup_numbers(name)? {
+            let backup_path: AbsolutePathBuf = self.backup_path_of(name, number)?;
+            match Self::load_file(&backup_path) {
+                Ok(playthrough) => {
+                    warn!(
+                        "Loaded playthrough \"{name}\" from the backup {} instead.",
+                        backup_path.display()
+                    );
+                    return Ok(self.prepared(playthrough, name));
+                }
+                Err(backup_report) => error!("{backup_report:#}"),
+            }
+        }
+
+        Err(report.wrap_err(format!(
+            "Failed to load playthrough \"{name}\" and none of its backups are valid."
+        )))
+    }
+
+    /// Prepare a loaded `Playthrough` for use.
+    ///
+    /// Makes sure it has the name of the file it was loaded from. The two can only differ if the
+    /// file was renamed by hand. The file name wins, because it is what every other method of
+    /// `PlaythroughStore` goes by.
+    ///
+    /// Also compacts its deleted saves whose retention period has expired (see
+    /// `Playthrough::compact_trash()`).
+    fn prepared(
+        &self,
+        mut playthrough: Playthrough,
+        name: &str,
+    ) -> Playthrough {
+        let _: usize = playthrough.compact_trash(self.trash_retention);
+        if playthrough.name() != name {
+            warn!(
+                "Playthrough \"{}\" is stored as \"{name}\". Renaming it to match.",
+                playthrough.name()
+            );
+            playthrough.set_name(name.to_owned());
+        }
+        playthrough
+    }
+
+    /// Load the `Playthrough` from the file at this path.
+    ///
+    /// Files written with an older format version are upgraded in memory (see
+    /// `Playthrough::MIGRATIONS`).
+    ///
+    /// # Errors
+    /// If reading the file fails.
+    /// If the file doesn't contain a valid `Playthrough` (see `Playthrough::from_bytes_migrated()`).
+    fn load_file(path: &Path) -> Result<Playthrough> {
+        let (playthrough, report): (Playthrough, MigrationReport) = fs::read(path)
+            .map_err(Report::from)
+            .and_then(|bytes: Vec<u8>| Playthrough::from_bytes_migrated(&bytes))
+            .wrap_err_with(|| format!("Failed to load {}", path.display()))?;
+        if report.is_needed() {
+            info!(
+                "Upgraded {} from format version {} to {}. The file is upgraded the next time it is saved.",
+                path.display(),
+                report.from,
+                report.to
+            );
+        }
+        Ok(playthrough)
+    }
+}
@@ -0,0 +1,44 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    info,
+    structs::{MigrationReport, Playthrough, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Upgrade the file of the `Playthrough` with this name to the current
+    /// `Playthrough::FORMAT_VERSION`, and return what changed.
+    ///
+    /// Loading a `Playthrough` upgrades it in memory anyway, so this only makes the upgrade stick
+    /// without having to play it. If `dry_run` is set, nothing is written, and the returned
+    /// `MigrationReport` describes what would have changed. Backups aren't upgraded.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If reading the file fails, or it doesn't contain a valid `Playthrough` (see
+    /// `Playthrough::from_bytes_migrated()`).
+    /// If writing the upgraded `Playthrough` fails (see `PlaythroughStore::save()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn migrate(
+        &self,
+        name: &str,
+        dry_run: bool,
+    ) -> Result<MigrationReport> {
+        let (mut playthrough, report): (Playthrough, MigrationReport) = Playthrough::from_bytes_migrated(&self.read(name)?)?;
+
+        if report.is_needed() && !dry_run {
+            // Keeps `PlaythroughStore::save()` from writing it anywhere else.
+            playthrough.set_name(name.to_owned());
+            self.save(&playthrough)?;
+            info!(
+                "Migrated playthrough \"{name}\" from format version {} to {}.",
+                report.from, report.to
+            );
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,20 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+mod archive;
+mod copy;
+mod def;
+mod delete;
+mod ensure_dir;
+mod load;
+mod migrate;
+mod names;
+mod registry;
+mod rotate_backups;
+mod save;
+mod summaries;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use def::PlaythroughStore;
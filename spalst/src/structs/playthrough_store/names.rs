@@ -0,0 +1,67 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::PlaythroughStore;
+use color_eyre::eyre::Result;
+use std::{
+    ffi::OsStr,
+    fs::{self, DirEntry},
+    path::PathBuf,
+};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Get the names of all stored `Playthrough`s, sorted.
+    ///
+    /// # Errors
+    /// If reading the directory fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn names(&self) -> Result<Vec<String>> {
+        let mut names: Vec<String> = Vec::new();
+
+        for entry in fs::read_dir(&self.dir)? {
+            let path: PathBuf = entry.map(|entry: DirEntry| entry.path())?;
+            if path.extension() != Some(OsStr::new(Self::EXTENSION)) {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(OsStr::to_str) {
+                names.push(name.to_owned());
+            }
+        }
+
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Get the numbers of the backups of the `Playthrough` with this name, from newest to oldest.
+    ///
+    /// Numbers can be missing in between, for example if a backup was deleted by hand.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If reading the directory fails.
+    pub(super) fn backup_numbers(
+        &self,
+        name: &str,
+    ) -> Result<Vec<usize>> {
+        Self::ensure_valid_name(name)?;
+        let prefix: String = format!("{name}.{}.bak.", Self::EXTENSION);
+        let mut numbers: Vec<usize> = Vec::new();
+
+        for entry in fs::read_dir(&self.dir)? {
+            let path: PathBuf = entry.map(|entry: DirEntry| entry.path())?;
+            if let Some(number) = path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|file_name: &str| file_name.strip_prefix(&prefix))
+                .and_then(|number: &str| number.parse().ok())
+            {
+                numbers.push(number);
+            }
+        }
+
+        numbers.sort_unstable();
+        Ok(numbers)
+    }
+}
@@ -0,0 +1,59 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    debug,
+    structs::{AbsolutePathBuf, PlaythroughRegistry, PlaythroughStore},
+};
+use color_eyre::eyre::{Result, WrapErr as _};
+use std::{
+    fs::{self, File},
+    io::Write as _,
+};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// The name of the file the `PlaythroughRegistry` is stored in. Doesn't have
+    /// `PlaythroughStore::EXTENSION` as its extension, so it is never taken for a `Playthrough`.
+    pub const REGISTRY_FILE: &str = "registry.json";
+
+    /// Load the `PlaythroughRegistry`. An empty one is returned if none was stored yet.
+    ///
+    /// # Errors
+    /// If reading or deserializing the file fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn load_registry(&self) -> Result<PlaythroughRegistry> {
+        let path: AbsolutePathBuf = self.dir.join(Self::REGISTRY_FILE);
+        if !path.try_exists()? {
+            return Ok(PlaythroughRegistry::default());
+        }
+        let bytes: Vec<u8> = fs::read(&path).wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_slice(&bytes).wrap_err_with(|| format!("Failed to deserialize {}", path.display()))
+    }
+
+    /// Write the `PlaythroughRegistry`, replacing the previous version.
+    ///
+    /// Like `PlaythroughStore::save()`, it is written to a temporary file first, so a crash never
+    /// leaves half of it behind. No backups are kept.
+    ///
+    /// # Errors
+    /// If serializing, writing, syncing or renaming fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn save_registry(
+        &self,
+        registry: &PlaythroughRegistry,
+    ) -> Result<()> {
+        let path: AbsolutePathBuf = self.dir.join(Self::REGISTRY_FILE);
+        let temp_path: AbsolutePathBuf = self.dir.join(format!(".{}.tmp", Self::REGISTRY_FILE));
+        {
+            let mut file: File = File::create(&temp_path)?;
+            file.write_all(&serde_json::to_vec_pretty(registry)?)?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, &path)?;
+        self.sync_dir()?;
+        debug!("Saved the playthrough registry to {}", path.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    debug,
+    structs::{AbsolutePathBuf, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+use std::fs;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Shift every backup of the `Playthrough` with this name up by one, and make its current file
+    /// the newest backup (number 1).
+    ///
+    /// The current file is hard linked rather than moved, so it stays in place until it is
+    /// replaced. It is copied instead on file systems that don't support hard links. Backups that would end up past `self.backups` are deleted.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If deleting, renaming or copying any of the files fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub(super) fn rotate_backups(
+        &self,
+        name: &str,
+    ) -> Result<()> {
+        if self.backups == 0 {
+            return self.remove_backups_from(name, 1);
+        }
+
+        self.remove_backups_from(name, self.backups)?;
+        for number in (1..self.backups).rev() {
+            let backup_path: AbsolutePathBuf = self.backup_path_of(name, number)?;
+            if backup_path.try_exists()? {
+                fs::rename(&backup_path, self.backup_path_of(name, number + 1)?)?;
+            }
+        }
+        let path: AbsolutePathBuf = self.path_of(name)?;
+        let newest_backup_path: AbsolutePathBuf = self.backup_path_of(name, 1)?;
+        if let Err(error) = fs::hard_link(&path, &newest_backup_path) {
+            debug!(
+                "Couldn't hard link {}, copying it instead: {error}",
+                path.display()
+            );
+            let _: u64 = fs::copy(&path, &newest_backup_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete the backup with this number of the `Playthrough` with this name, along with every
+    /// older one (see `PlaythroughStore::backup_numbers()`).
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If deleting any of the backups fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub(super) fn remove_backups_from(
+        &self,
+        name: &str,
+        first: usize,
+    ) -> Result<()> {
+        for number in self.backup_numbers(name)? {
+            if number < first {
+                continue;
+            }
+            let backup_path: AbsolutePathBuf = self.backup_path_of(name, number)?;
+            fs::remove_file(&backup_path)?;
+            debug!("Removed backup {}", backup_path.display());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    debug,
+    structs::{AbsolutePathBuf, Playthrough, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+use std::{
+    fs::{self, File},
+    io::Write as _,
+};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Write this `Playthrough` to its file, replacing the previous version if one exists.
+    ///
+    /// The new version is first written to a temporary file and synced to disk, and only then
+    /// renamed over the old one. A crash at any point therefore leaves either the old or the new
+    /// version intact. The old version becomes the newest backup (see
+    /// `PlaythroughStore::rotate_backups()`).
+    ///
+    /// # Errors
+    /// If the name of the `Playthrough` isn't valid.
+    /// If serializing, writing, syncing or renaming fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self, playthrough)))]
+    pub fn save(
+        &self,
+        playthrough: &Playthrough,
+    ) -> Result<()> {
+        let path: AbsolutePathBuf = self.path_of(playthrough.name())?;
+        let temp_path: AbsolutePathBuf = self.temp_path_of(playthrough.name())?;
+
+        {
+            let mut file: File = File::create(&temp_path)?;
+            file.write_all(&playthrough.to_bytes()?)?;
+            file.sync_all()?;
+        }
+        if path.try_exists()? {
+            self.rotate_backups(playthrough.name())?;
+        }
+        fs::rename(&temp_path, &path)?;
+        self.sync_dir()?;
+
+        debug!(
+            "Saved playthrough \"{}\" to {}",
+            playthrough.name(),
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// Sync the directory of this `PlaythroughStore`, so that renames inside of it are on disk.
+    ///
+    /// # Errors
+    /// If opening or syncing the directory fails.
+    pub(super) fn sync_dir(&self) -> Result<()> {
+        // Directories can't be opened as files on Windows, and renames there are durable once they
+        // return anyway.
+        #[cfg(unix)]
+        File::open(&self.dir)?.sync_all()?;
+        Ok(())
+    }
+}
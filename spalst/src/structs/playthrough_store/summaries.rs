@@ -0,0 +1,31 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    error,
+    structs::{PlaythroughStore, PlaythroughSummary},
+};
+use color_eyre::eyre::Result;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Load every stored `Playthrough` and summarize it, sorted by name.
+    ///
+    /// `Playthrough`s that fail to load (even from their backups) are logged and skipped, so that
+    /// one broken file doesn't hide all the others.
+    ///
+    /// # Errors
+    /// If listing the stored `Playthrough`s fails.
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn summaries(&self) -> Result<Vec<PlaythroughSummary>> {
+        let mut summaries: Vec<PlaythroughSummary> = Vec::new();
+        for name in self.names()? {
+            match self.load(&name) {
+                Ok(playthrough) => summaries.push((&playthrough).into()),
+                Err(report) => error!("Skipping playthrough \"{name}\": {report:#}"),
+            }
+        }
+        Ok(summaries)
+    }
+}
@@ -0,0 +1,142 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+//!
+//! Unit tests for `PlaythroughStore`.
+
+use crate::structs::{AbsolutePathBuf, Playthrough, PlaythroughStore, SaveId};
+use color_eyre::eyre::Result;
+use core::time::Duration;
+use std::{env, fs, process};
+
+/// The name of the `Playthrough` every test stores.
+const NAME: &str = "test";
+
+/// Make a `PlaythroughStore` that keeps this many backups, in an empty directory of its own.
+fn empty_store(
+    test: &str,
+    backups: usize,
+) -> Result<PlaythroughStore> {
+    let dir: AbsolutePathBuf = AbsolutePathBuf::try_new(env::temp_dir().join(format!("spalst-{}-{test}", process::id())))?;
+    if dir.try_exists()? {
+        fs::remove_dir_all(&dir)?;
+    }
+    let store: PlaythroughStore = PlaythroughStore::new(dir, backups, Duration::from_hours(1));
+    store.ensure_dir()?;
+    Ok(store)
+}
+
+/// Save a `Playthrough` this many times, forking its root save before every save after the first.
+/// Returns the bytes of every version that was saved, from oldest to newest.
+fn save_versions(
+    store: &PlaythroughStore,
+    count: usize,
+) -> Result<Vec<Vec<u8>>> {
+    let mut playthrough: Playthrough = Playthrough::new(NAME.to_owned());
+    let root: SaveId = playthrough.make_root_node()?;
+    let mut versions: Vec<Vec<u8>> = Vec::with_capacity(count);
+    for _ in 0..count {
+        if !versions.is_empty() {
+            let _: SaveId = playthrough.fork_save(root)?;
+        }
+        store.save(&playthrough)?;
+        versions.push(playthrough.to_bytes()?);
+    }
+    Ok(versions)
+}
+
+/// Saving replaces the file through a temporary one, and keeps the previous versions as backups,
+/// newest first, up to the limit.
+#[test]
+fn save_rotates_backups() -> Result<()> {
+    let store: PlaythroughStore = empty_store("save_rotates_backups", 2)?;
+    // Left behind by a crash in the middle of a save.
+    fs::write(store.temp_path_of(NAME)?, b"garbage")?;
+    let versions: Vec<Vec<u8>> = save_versions(&store, 4)?;
+
+    for (path, expected) in [
+        (store.path_of(NAME)?, versions.get(3)),
+        (store.backup_path_of(NAME, 1)?, versions.get(2)),
+        (store.backup_path_of(NAME, 2)?, versions.get(1)),
+    ] {
+        assert_eq!(
+            Some(&fs::read(&path)?),
+            expected,
+            "{} must hold the right version.",
+            path.display()
+        );
+    }
+    assert!(
+        !store.backup_path_of(NAME, 3)?.try_exists()?,
+        "Backups past the limit must be deleted."
+    );
+    assert!(
+        !store.temp_path_of(NAME)?.try_exists()?,
+        "The temporary file must be renamed over the real one."
+    );
+    fs::remove_dir_all(&store.dir)?;
+    Ok(())
+}
+
+/// A file that can't be loaded is replaced by its newest valid backup, even past a missing one.
+#[test]
+fn load_falls_back_to_backup() -> Result<()> {
+    let store: PlaythroughStore = empty_store("load_falls_back_to_backup", 3)?;
+    let versions: Vec<Vec<u8>> = save_versions(&store, 3)?;
+    fs::write(store.path_of(NAME)?, b"garbage")?;
+    fs::remove_file(store.backup_path_of(NAME, 1)?)?;
+
+    assert_eq!(
+        Some(&store.load(NAME)?.to_bytes()?),
+        versions.first(),
+        "The oldest backup must be loaded, since it is the only valid one."
+    );
+    fs::write(store.backup_path_of(NAME, 2)?, b"garbage")?;
+    assert!(
+        store.load(NAME).is_err(),
+        "Loading must fail once no backup is valid."
+    );
+    fs::remove_dir_all(&store.dir)?;
+    Ok(())
+}
+
+/// Renaming a `Playthrough` takes its backups along, and leaves nothing under the old name.
+#[test]
+fn rename_moves_backups() -> Result<()> {
+    let store: PlaythroughStore = empty_store("rename_moves_backups", 3)?;
+    let _: Vec<Vec<u8>> = save_versions(&store, 3)?;
+
+    store.rename(NAME, "renamed")?;
+    assert_eq!(
+        store.names()?,
+        ["renamed"],
+        "Only the new name may be stored."
+    );
+    assert_eq!(
+        store.backup_numbers("renamed")?,
+        [1, 2],
+        "The backups must be moved to the new name."
+    );
+    assert!(
+        store.backup_numbers(NAME)?.is_empty(),
+        "No backup may be left under the old name."
+    );
+    fs::remove_dir_all(&store.dir)?;
+    Ok(())
+}
+
+/// Deleting a `Playthrough` deletes every one of its backups, even past a missing one.
+#[test]
+fn delete_removes_every_backup() -> Result<()> {
+    let store: PlaythroughStore = empty_store("delete_removes_every_backup", 3)?;
+    let _: Vec<Vec<u8>> = save_versions(&store, 4)?;
+    fs::remove_file(store.backup_path_of(NAME, 1)?)?;
+
+    store.delete(NAME)?;
+    assert_eq!(
+        fs::read_dir(&store.dir)?.count(),
+        0,
+        "No file of the deleted playthrough may be left."
+    );
+    fs::remove_dir_all(&store.dir)?;
+    Ok(())
+}
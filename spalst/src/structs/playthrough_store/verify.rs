@@ -0,0 +1,60 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::IntegrityProblem,
+    info,
+    structs::{IntegrityReport, Playthrough, PlaythroughStore},
+};
+use color_eyre::eyre::Result;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl PlaythroughStore {
+    /// Check the file of the `Playthrough` with this name for structural problems (see
+    /// `Playthrough::verify()`). Its backups aren't checked.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If reading the file fails, or it can't be checked (see `Playthrough::verify()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn verify(
+        &self,
+        name: &str,
+    ) -> Result<IntegrityReport> {
+        Playthrough::verify(&self.read(name)?)
+    }
+
+    /// Repair the file of the `Playthrough` with this name (see `Playthrough::repair()`).
+    ///
+    /// The file is only written if something could be repaired, in which case the damaged version
+    /// is kept as the newest backup. Returns the problems that were found, along with the ones that
+    /// are left.
+    ///
+    /// # Errors
+    /// If the name isn't valid.
+    /// If reading the file fails, or it can't be repaired (see `Playthrough::repair()`).
+    /// If writing the repaired `Playthrough` fails (see `PlaythroughStore::save()`).
+    #[cfg_attr(feature = "logging", instrument(skip(self)))]
+    pub fn repair(
+        &self,
+        name: &str,
+    ) -> Result<(IntegrityReport, IntegrityReport)> {
+        let bytes: Vec<u8> = self.read(name)?;
+        let found: IntegrityReport = Playthrough::verify(&bytes)?;
+        if !found.problems.iter().any(IntegrityProblem::is_repairable) {
+            return Ok((found.clone(), found));
+        }
+
+        let (mut playthrough, left): (Playthrough, IntegrityReport) = Playthrough::repair(&bytes)?;
+        // Keeps `PlaythroughStore::save()` from writing it anywhere else.
+        playthrough.set_name(name.to_owned());
+        self.save(&playthrough)?;
+        info!(
+            "Repaired playthrough \"{name}\". {} of {} problems are left.",
+            left.problems.len(),
+            found.problems.len()
+        );
+        Ok((found, left))
+    }
+}
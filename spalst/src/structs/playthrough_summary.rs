@@ -0,0 +1,33 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::Playthrough;
+use chrono::{DateTime, Utc};
+use core::time::Duration;
+
+/// What `ManagePlaythroughsScreen` shows about a stored `Playthrough`.
+///
+/// Loading every `Playthrough` each frame would be far too slow, so these are kept in `AppState`
+/// instead, and refreshed whenever a `PlaythroughRequest` is handled.
+#[derive(Clone, Debug)]
+pub struct PlaythroughSummary {
+    /// The name of the `Playthrough`.
+    pub name: String,
+    /// The playtime of every `Save` in the `Playthrough` added together.
+    pub playtime: Duration,
+    /// When the `Playthrough` was created, if it has a root `Save`.
+    pub created: Option<DateTime<Utc>>,
+    /// The last time any `Save` of the `Playthrough` was played, if ever.
+    pub last_played: Option<DateTime<Utc>>,
+}
+
+impl From<&Playthrough> for PlaythroughSummary {
+    fn from(playthrough: &Playthrough) -> Self {
+        Self {
+            name: playthrough.name().to_owned(),
+            playtime: playthrough.total_playtime(),
+            created: playthrough.created(),
+            last_played: playthrough.last_played(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use core::time::Duration;
+use std::time::Instant;
+
+/// Measures how long the active save has been played for, since its playtime was last committed.
+///
+/// The clock only runs while the save is actually being played. It is paused while the window
+/// doesn't have focus, or while a `Screen` that isn't gameplay (such as `PauseScreen`) is shown.
+#[derive(Debug, Default)]
+pub struct PlaytimeClock {
+    /// When the clock was last started, if it is running.
+    running_since: Option<Instant>,
+    /// The time measured before the clock was last paused.
+    banked: Duration,
+}
+
+impl PlaytimeClock {
+    /// Start the clock if `running` is `true`, or pause it otherwise. Does nothing if it is already
+    /// in that state.
+    pub fn set_running(
+        &mut self,
+        running: bool,
+    ) {
+        match (running, self.running_since) {
+            (true, None) => self.running_since = Some(Instant::now()),
+            (false, Some(since)) => {
+                self.banked = self.banked.saturating_add(since.elapsed());
+                self.running_since = None;
+            }
+            (true, Some(_)) | (false, None) => {}
+        }
+    }
+
+    /// Get the time measured since the clock was last reset.
+    pub fn elapsed(&self) -> Duration {
+        self.running_since.map_or(self.banked, |since: Instant| {
+            self.banked.saturating_add(since.elapsed())
+        })
+    }
+
+    /// Reset the clock to zero, and return the time it measured. A running clock keeps running.
+    pub fn take(&mut self) -> Duration {
+        let now: Instant = Instant::now();
+        let elapsed: Duration = self.running_since.map_or(self.banked, |since: Instant| {
+            self.banked
+                .saturating_add(now.saturating_duration_since(since))
+        });
+        self.banked = Duration::ZERO;
+        if self.running_since.is_some() {
+            self.running_since = Some(now);
+        }
+        elapsed
+    }
+}
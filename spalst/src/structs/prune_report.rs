@@ -0,0 +1,13 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use core::time::Duration;
+
+/// What `Playthrough::prune()` removed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PruneReport {
+    /// How many saves were removed.
+    pub removed: usize,
+    /// The playtime of the removed saves added together. It isn't counted anywhere anymore.
+    pub dropped_playtime: Duration,
+}
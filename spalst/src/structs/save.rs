@@ -1,14 +1,16 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
+use crate::structs::SaveMetadata;
 use chrono::{DateTime, Utc};
 use core::{marker::PhantomData, time::Duration};
 use derive_new::new;
+use serde::{Deserialize, Serialize};
 
 /// A save.
 ///
 /// Contains the world, along with useful metadata.
-#[derive(Debug, new)]
+#[derive(Clone, Debug, Deserialize, Serialize, new)]
 pub struct Save {
     /// The playtime on this `Save`. Does not include the playtime of its parent.
     #[new(default)]
@@ -19,6 +21,21 @@ pub struct Save {
     #[new(value = "Utc::now()")]
     birth_time: DateTime<Utc>,
 
+    /// Whether this `Save` was forked.
+    ///
+    /// A sealed `Save` can't be played anymore, only forked again (see `Playthrough`).
+    #[new(default)]
+    sealed: bool,
+
+    /// Whether this `Save` was forked automatically, by an autosave (see
+    /// `Playthrough::autosave()`), instead of by the user.
+    #[new(default)]
+    autosave: bool,
+
+    /// What the user wrote down about this `Save`.
+    #[new(default)]
+    metadata: SaveMetadata,
+
     /// Game info that doesn't exist yet.
     game_info: PhantomData<u8>,
 }
@@ -29,16 +46,60 @@ impl Save {
         self.playtime
     }
 
+    /// Add this much time to the playtime of this `Save`.
+    pub const fn add_playtime(
+        &mut self,
+        playtime: Duration,
+    ) {
+        self.playtime = self.playtime.saturating_add(playtime);
+    }
+
     /// Get the birth time of this `Save`.
     pub const fn get_birth_time(&self) -> DateTime<Utc> {
         self.birth_time
     }
 
+    /// Check whether this `Save` was forked.
+    pub const fn is_sealed(&self) -> bool {
+        self.sealed
+    }
+
+    /// Mark this `Save` as forked, so it can't be played anymore.
+    pub const fn seal(&mut self) {
+        self.sealed = true;
+    }
+
+    /// Check whether this `Save` was made by an autosave.
+    pub const fn is_autosave(&self) -> bool {
+        self.autosave
+    }
+
+    /// Mark this `Save` as made by an autosave.
+    pub const fn mark_autosave(&mut self) {
+        self.autosave = true;
+    }
+
+    /// Get what the user wrote down about this `Save`.
+    pub const fn metadata(&self) -> &SaveMetadata {
+        &self.metadata
+    }
+
+    /// Get what the user wrote down about this `Save`, mutably.
+    pub const fn metadata_mut(&mut self) -> &mut SaveMetadata {
+        &mut self.metadata
+    }
+
     /// Make a child from this `Save` and return it.
+    ///
+    /// The child isn't sealed or marked as an autosave, even if this `Save` is, and starts
+    /// without any `SaveMetadata`.
     pub fn mk_child(&self) -> Self {
         Self {
             playtime: Duration::ZERO,
             birth_time: Utc::now(),
+            sealed: false,
+            autosave: false,
+            metadata: SaveMetadata::default(),
             game_info: self.game_info,
         }
     }
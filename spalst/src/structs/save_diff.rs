@@ -0,0 +1,24 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{enums::SaveChange, structs::SaveId};
+
+/// What changed between two saves of the same `Playthrough`, found by `Playthrough::diff()`.
+#[derive(Clone, Debug)]
+pub struct SaveDiff {
+    /// The older save.
+    pub from: SaveId,
+    /// The newer save.
+    pub to: SaveId,
+    /// The newest save both of them descend from (see `Playthrough::common_ancestor()`).
+    pub common_ancestor: Option<SaveId>,
+    /// Every difference between them, ordered by path.
+    pub changes: Vec<SaveChange>,
+}
+
+impl SaveDiff {
+    /// Check whether the saves are the same.
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
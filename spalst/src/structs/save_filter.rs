@@ -0,0 +1,38 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{enums::ColorTag, structs::SaveMetadata};
+
+/// Which saves to look for, by their `SaveMetadata` (see `Playthrough::search()`).
+///
+/// An empty `SaveFilter` matches every save.
+#[derive(Clone, Debug, Default)]
+pub struct SaveFilter {
+    /// Text that the name or the notes have to contain, ignoring case. Ignored if empty.
+    pub text: String,
+    /// Colours that saves have to be tagged with, all of them.
+    pub tags: Vec<ColorTag>,
+    /// Whether only pinned saves match.
+    pub pinned_only: bool,
+}
+
+impl SaveFilter {
+    /// Check whether this `SaveFilter` matches every save.
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty() && self.tags.is_empty() && !self.pinned_only
+    }
+
+    /// Check whether a save with this `SaveMetadata` matches.
+    pub fn matches(
+        &self,
+        metadata: &SaveMetadata,
+    ) -> bool {
+        let text: String = self.text.trim().to_lowercase();
+        (!self.pinned_only || metadata.pinned)
+            && self
+                .tags
+                .iter()
+                .all(|tag: &ColorTag| metadata.has_tag(*tag))
+            && (text.is_empty() || metadata.name.to_lowercase().contains(&text) || metadata.notes.to_lowercase().contains(&text))
+    }
+}
@@ -5,7 +5,7 @@ use core::ops::{Deref, DerefMut};
 use indextree::NodeId;
 
 /// A wrapper over `indextree::NodeId`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct SaveId(NodeId);
 
 impl Deref for SaveId {
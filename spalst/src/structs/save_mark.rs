@@ -0,0 +1,18 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A save on the path from the root save to the save that was played last, as remembered by a
+/// `PlaythroughRegistry` (see `Playthrough::index_path()`).
+///
+/// The indexes of removed saves are reused by new ones, so the birth time of the save is kept
+/// along with its index, to tell whether the index still points to the same save.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SaveMark {
+    /// The index of the save (see `SaveId`).
+    pub index: usize,
+    /// The birth time of the save.
+    pub birth_time: DateTime<Utc>,
+}
@@ -0,0 +1,44 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::enums::ColorTag;
+use serde::{Deserialize, Serialize};
+
+/// What the user wrote down about a save, to tell it apart from the others.
+///
+/// Kept when the save is deleted (see `DeletedSave`), so the tree still makes sense afterwards.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SaveMetadata {
+    /// The name of the save. Empty if it wasn't named, in which case its index is shown instead.
+    pub name: String,
+    /// Free-form notes about the save.
+    pub notes: String,
+    /// The colours the save is tagged with, in the order they were added in.
+    pub tags: Vec<ColorTag>,
+    /// Whether the save is pinned.
+    ///
+    /// Pinned autosaves are never merged away (see `Playthrough::autosave()`).
+    pub pinned: bool,
+}
+
+impl SaveMetadata {
+    /// Check whether the save is tagged with this colour.
+    pub fn has_tag(
+        &self,
+        tag: ColorTag,
+    ) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Tag the save with this colour, or untag it if it already is.
+    pub fn toggle_tag(
+        &mut self,
+        tag: ColorTag,
+    ) {
+        if self.has_tag(tag) {
+            self.tags.retain(|other: &ColorTag| *other != tag);
+        } else {
+            self.tags.push(tag);
+        }
+    }
+}
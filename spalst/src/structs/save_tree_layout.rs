@@ -0,0 +1,75 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::{Playthrough, SaveId};
+use egui::{Pos2, Rect, Vec2, vec2};
+
+/// Where every save of a `Playthrough` is drawn by `SaveTreeScreen`.
+///
+/// Each tree grows downwards from its root. Leaves are put in consecutive columns, and every
+/// parent is centered above its children.
+#[derive(Debug)]
+pub struct SaveTreeLayout {
+    /// The rectangle of every save.
+    pub nodes: Vec<(SaveId, Rect)>,
+    /// The lines connecting every parent to its children, from the parent to the child.
+    pub edges: Vec<[Pos2; 2]>,
+}
+
+impl SaveTreeLayout {
+    /// The size of the rectangle of a save.
+    pub const NODE_SIZE: Vec2 = vec2(170.0, 64.0);
+    /// The distance between the corners of neighbouring columns and rows.
+    const SPACING: Vec2 = vec2(200.0, 110.0);
+
+    /// Lay out every save of this `Playthrough`.
+    pub fn new(playthrough: &Playthrough) -> Self {
+        let mut instance: Self = Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+
+        let mut next_column: u16 = 0;
+        for root in playthrough.tree_roots() {
+            let _: (f32, Rect) = instance.place(playthrough, root, 0, &mut next_column);
+        }
+
+        instance
+    }
+
+    /// Lay out the save this `SaveId` points to, along with its descendants. Returns its column
+    /// and its rectangle.
+    fn place(
+        &mut self,
+        playthrough: &Playthrough,
+        id: SaveId,
+        depth: u16,
+        next_column: &mut u16,
+    ) -> (f32, Rect) {
+        // `id` comes from `playthrough` itself, so getting its children can't fail.
+        let children: Vec<(f32, Rect)> = playthrough
+            .children(id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child: SaveId| self.place(playthrough, child, depth.saturating_add(1), next_column))
+            .collect();
+
+        let column: f32 = if let (Some(&(first, _)), Some(&(last, _))) = (children.first(), children.last()) {
+            f32::midpoint(first, last)
+        } else {
+            let column: u16 = *next_column;
+            *next_column = next_column.saturating_add(1);
+            f32::from(column)
+        };
+        let rect: Rect = Rect::from_min_size(
+            (vec2(column, f32::from(depth)) * Self::SPACING).to_pos2(),
+            Self::NODE_SIZE,
+        );
+
+        for (_, child) in children {
+            self.edges.push([rect.center_bottom(), child.center_top()]);
+        }
+        self.nodes.push((id, rect));
+        (column, rect)
+    }
+}
@@ -4,7 +4,7 @@
 use core::ops::{Deref, DerefMut};
 
 /// An ID for a `Screen` in `ScreenManager`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct ScreenId(usize);
 
 impl Deref for ScreenId {
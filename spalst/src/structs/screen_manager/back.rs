@@ -1,27 +1,99 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::structs::{ScreenId, ScreenManager};
+use crate::{
+    enums::{NavigationDirection, Request},
+    structs::{ScreenId, ScreenManager, ScreenNode},
+};
 use color_eyre::eyre::{OptionExt as _, Result};
+use core::any::TypeId;
 #[cfg(feature = "logging")]
 use tracing::instrument;
 
 impl ScreenManager {
     /// Go back to the previous screen.
     ///
+    /// The screen that is left is exited, and the previous one is resumed (see `Screen`). The
+    /// `Request`s they make are pushed to `requests`. Going forward leads to the screen that is
+    /// left afterwards.
+    ///
     /// # Errors
-    /// If the current screen is the root screen.
-    #[cfg_attr(feature = "logging", instrument)]
-    pub fn back(&mut self) -> Result<ScreenId> {
-        {
-            self.current_id = *self
-                .path_from_root
-                .iter()
-                .rev()
-                .nth(1)
-                .ok_or_eyre("Can't go back from root screen.")?;
+    /// If there's no screen to go back to.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn back(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) -> Result<ScreenId> {
+        self.back_steps(1, requests)
+    }
+
+    /// Go back this many screens at once. Does nothing if it is 0.
+    ///
+    /// Like going back one screen at a time, the current screen and every screen that is skipped
+    /// over are exited (see `Screen`), but only the screen that is gone back to is resumed. Going
+    /// forward leads to the skipped screens afterwards, in the order they were gone back from.
+    ///
+    /// # Errors
+    /// If there aren't that many screens to go back to.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn back_steps(
+        &mut self,
+        steps: usize,
+        requests: &mut Vec<Request>,
+    ) -> Result<ScreenId> {
+        if steps == 0 {
+            return Ok(self.current_id);
+        }
+        let index: usize = self
+            .back_stack
+            .len()
+            .checked_sub(steps)
+            .ok_or_eyre(format!("There aren't {steps} screens to go back to."))?;
+        Ok(self.back_to_index(index, requests))
+    }
+
+    /// Go back to the nearest screen in the history whose type has this `TypeId`, as if going back
+    /// until it is reached. Hooks are called like in `self.back_steps()`.
+    ///
+    /// # Errors
+    /// If there's no screen of that type to go back to.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn back_to_screen_type(
+        &mut self,
+        type_id: TypeId,
+        requests: &mut Vec<Request>,
+    ) -> Result<ScreenId> {
+        let index: usize = self
+            .back_stack
+            .iter()
+            .rposition(|id: &ScreenId| {
+                self.get_screen_node(*id)
+                    .is_some_and(|node: &ScreenNode| node.screen_type_id() == type_id)
+            })
+            .ok_or_eyre("There's no screen of the passed type to go back to.")?;
+        Ok(self.back_to_index(index, requests))
+    }
+
+    /// Go back to the screen at this valid index of the `back_stack`.
+    fn back_to_index(
+        &mut self,
+        index: usize,
+        requests: &mut Vec<Request>,
+    ) -> ScreenId {
+        let skipped: Vec<ScreenId> = self.back_stack.split_off(index.saturating_add(1));
+        let Some(id) = self.back_stack.pop() else {
+            unreachable!("The index ({index}) must point into the `back_stack`.");
+        };
+        let from_id: ScreenId = self.current_id;
+        self.exit(from_id, requests);
+        for skipped_id in skipped.iter().rev() {
+            self.exit(*skipped_id, requests);
         }
-        let _: Option<ScreenId> = self.path_from_root.pop();
-        Ok(self.current_id)
+        self.forward_stack.push(from_id);
+        self.forward_stack.extend(skipped.into_iter().rev());
+        self.current_id = id;
+        self.start_transition(from_id, NavigationDirection::Back);
+        self.show(id, requests);
+        self.current_id
     }
 }
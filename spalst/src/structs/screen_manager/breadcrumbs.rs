@@ -0,0 +1,92 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::ScreenManagerRequest,
+    structs::{ScreenId, ScreenManager},
+};
+use core::iter;
+use egui::{InnerResponse, Response, Ui};
+
+impl ScreenManager {
+    /// Whether the breadcrumb bar is shown above the current `Screen`.
+    pub const fn show_breadcrumbs(&self) -> bool {
+        self.show_breadcrumbs
+    }
+
+    /// Show or hide the breadcrumb bar above the current `Screen`.
+    pub const fn set_show_breadcrumbs(
+        &mut self,
+        show: bool,
+    ) {
+        self.show_breadcrumbs = show;
+    }
+
+    /// Get the title of the window, which follows the current `Screen` (see `Screen::title()`).
+    pub fn window_title(&self) -> String {
+        if self.current_id == self.root_id {
+            "Spalst".to_owned()
+        } else {
+            format!("{} - Spalst", self.get_current_screen_node().screen.title())
+        }
+    }
+
+    /// Get the `Screen`s the breadcrumb bar shows: every one the user went through to get to the
+    /// current one (leaving out the root, which is empty), and then the current one. Each comes
+    /// with how many steps back it is.
+    pub fn breadcrumbs(&self) -> Vec<(ScreenId, usize)> {
+        let depth: usize = self.back_stack.len();
+        self.back_stack
+            .iter()
+            .copied()
+            .chain(iter::once(self.current_id))
+            .enumerate()
+            .filter(|&(_, id): &(usize, ScreenId)| id != self.root_id)
+            .map(|(index, id): (usize, ScreenId)| (id, depth.saturating_sub(index)))
+            .collect()
+    }
+
+    /// Get the `ScreenManagerRequest` to go back to the breadcrumb this many steps back (see
+    /// `self.breadcrumbs()`), if it can be clicked.
+    ///
+    /// The current `Screen` can't be clicked, and neither can anything while it is a gameplay
+    /// screen (see `Screen::is_gameplay()`), since playing has to be stopped through the game
+    /// itself.
+    pub fn breadcrumb_request(
+        &self,
+        steps: usize,
+    ) -> Option<ScreenManagerRequest> {
+        (steps > 0 && !self.get_current_screen_node().screen.is_gameplay()).then_some(ScreenManagerRequest::BackSteps(steps))
+    }
+
+    /// Render the breadcrumb bar (see `self.breadcrumbs()`), where the title of every `Screen` that
+    /// can be gone back to (see `self.breadcrumb_request()`) can be clicked to do so. Returns the
+    /// `ScreenManagerRequest` to do so, if one was clicked.
+    pub fn breadcrumbs_ui(
+        &self,
+        ui: &mut Ui,
+    ) -> Option<ScreenManagerRequest> {
+        let mut request: Option<ScreenManagerRequest> = None;
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            for (id, steps) in self.breadcrumbs() {
+                let Some(node) = self.get_screen_node(id) else {
+                    continue;
+                };
+                if steps == 0 {
+                    let _: Response = ui.strong(node.screen.title());
+                } else {
+                    if let Some(crumb_request) = self.breadcrumb_request(steps) {
+                        if ui.link(node.screen.title()).clicked() {
+                            request = Some(crumb_request);
+                        }
+                    } else {
+                        let _: Response = ui.label(node.screen.title());
+                    }
+                    let _: Response = ui.label(">");
+                }
+            }
+        });
+        let _: Response = ui.separator();
+        request
+    }
+}
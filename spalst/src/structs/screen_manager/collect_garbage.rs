@@ -0,0 +1,75 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::{Overlay, ScreenId, ScreenManager, ScreenNode, Transition};
+use color_eyre::eyre::{OptionExt as _, Result};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl ScreenManager {
+    /// Whether the screen this `ScreenId` points to can't be reclaimed: if it is the root or the
+    /// current one, if it is in the history, if it is being switched away from (see `Transition`),
+    /// if an overlay was opened over it, if it is pinned, or if it was added but never shown.
+    pub fn is_reachable(
+        &self,
+        id: ScreenId,
+    ) -> bool {
+        self.get_screen_node(id).is_some_and(|node: &ScreenNode| {
+            id == self.root_id
+                || id == self.current_id
+                || self.back_stack.contains(&id)
+                || self.forward_stack.contains(&id)
+                || self
+                    .transition
+                    .is_some_and(|transition: Transition| transition.from_id == id)
+                || self
+                    .overlays
+                    .iter()
+                    .any(|overlay: &Overlay| overlay.owner_id == id)
+                || node.pinned
+                || !node.entered
+        })
+    }
+
+    /// Remove every `ScreenNode` that isn't reachable (see `self.is_reachable()`), so that their
+    /// `ScreenId`s can be reused. Returns how many were removed.
+    ///
+    /// The removed screens were already exited when they were gone back from (see `Screen` and
+    /// `ScreenManager::back_steps()`), so they are simply dropped.
+    #[cfg_attr(feature = "logging", instrument)]
+    pub fn collect_garbage(&mut self) -> usize {
+        let unreachable: Vec<ScreenId> = (0..self.screens.len())
+            .map(ScreenId::from)
+            .filter(|id: &ScreenId| self.get_screen_node(*id).is_some() && !self.is_reachable(*id))
+            .collect();
+        for id in &unreachable {
+            if let Some(slot) = self.screens.get_mut(**id) {
+                *slot = None;
+            }
+        }
+        for node in self.screens.iter_mut().flatten() {
+            node.forget(&unreachable);
+        }
+        unreachable.len()
+    }
+
+    /// Pin or unpin the screen this `ScreenId` points to. Pinned screens are never reclaimed (see
+    /// `self.collect_garbage()`).
+    ///
+    /// # Errors
+    /// If the `ScreenId` doesn't point to a `ScreenNode`.
+    #[cfg_attr(feature = "logging", instrument)]
+    pub fn set_pinned(
+        &mut self,
+        id: ScreenId,
+        pinned: bool,
+    ) -> Result<()> {
+        self.get_screen_node_mut(id)
+            .ok_or_eyre(format!(
+                "Passed ID ({}) doesn't point to a `ScreenNode`.",
+                *id
+            ))?
+            .pinned = pinned;
+        Ok(())
+    }
+}
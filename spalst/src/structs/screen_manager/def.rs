@@ -1,7 +1,7 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::structs::{ScreenId, ScreenNode};
+use crate::structs::{Overlay, ScreenId, ScreenNode, Transition, TransitionSettings};
 use color_eyre::eyre::{OptionExt as _, Result};
 #[cfg(feature = "logging")]
 use tracing::instrument;
@@ -11,20 +11,43 @@ use tracing::instrument;
 /// Simply having a `Vec<Box<dyn Screen>>` would work for display but wouldn't work
 #[derive(Debug)]
 pub struct ScreenManager {
-    /// `Screen` collection.
-    pub(super) screens: Vec<ScreenNode>,
-    /// The path the user took to get from the root `Screen` to the current one.
+    /// `Screen` collection, indexed by `ScreenId`.
     ///
-    /// The first element will always be the root node.
+    /// It is `None` where a `ScreenNode` was reclaimed (see `self.collect_garbage()`), so that its
+    /// `ScreenId` can be reused.
+    pub(super) screens: Vec<Option<ScreenNode>>,
+    /// The screens the user went through to get from the root `Screen` to the current one, which
+    /// isn't included. Going back leads to the last element.
+    ///
+    /// The first element will always be the root node, unless the `Vec` is empty.
     ///
     /// This is important because otherwise a "Back" button wouldn't be possible (since each node in
     /// `screens` can have multiple parents).
     ///
-    /// When the user presses a "Back" button, the last element of this `Vec` will be removed.
-    /// When they navigate back to an already-encountered screen (including the most recent one)
-    /// without pressing a "Back" button, the last element of this `Vec` will not be removed, and
-    /// instead a new element will be added.
-    pub(super) path_from_root: Vec<ScreenId>,
+    /// When the user goes back, the last element of this `Vec` will be removed. When they
+    /// navigate to any screen (including an already-encountered one) without going back or
+    /// forward, the current one will be added instead.
+    pub(super) back_stack: Vec<ScreenId>,
+    /// The screens the user went back from, so that they can go forward to them again. Going
+    /// forward leads to the last element.
+    ///
+    /// It is cleared whenever the user navigates to a screen without going back or forward, like a
+    /// browser does.
+    pub(super) forward_stack: Vec<ScreenId>,
+
+    /// The overlays shown over the current `Screen`, from the bottom one to the top one.
+    pub(super) overlays: Vec<Overlay>,
+
+    /// How switching `Screen`s is animated.
+    pub(super) transition_settings: TransitionSettings,
+    /// The switch to the current `Screen` that is being animated, if any.
+    pub(super) transition: Option<Transition>,
+    /// How many `Transition`s were started.
+    pub(super) transitions_started: u64,
+
+    /// Whether to show the breadcrumb bar above the current `Screen` (see
+    /// `ScreenManager::breadcrumbs_ui()`).
+    pub(super) show_breadcrumbs: bool,
 
     /// The first `Screen`.
     pub(super) root_id: ScreenId,
@@ -43,9 +66,38 @@ impl ScreenManager {
         self.current_id
     }
 
-    /// The next available `ScreenId`.
+    /// Returns the `ScreenId` of the `Screen` that going back leads to, unless the current
+    /// `Screen` is the root one.
+    pub fn previous_id(&self) -> Option<ScreenId> {
+        self.back_stack.last().copied()
+    }
+
+    /// Returns the `ScreenId` of the `Screen` that going forward leads to, if one.
+    pub fn forward_id(&self) -> Option<ScreenId> {
+        self.forward_stack.last().copied()
+    }
+
+    /// The next available `ScreenId`. Reclaimed ones are reused first.
     pub(super) fn next_id(&self) -> ScreenId {
-        self.screens.len().into()
+        self.screens
+            .iter()
+            .position(Option::is_none)
+            .unwrap_or(self.screens.len())
+            .into()
+    }
+
+    /// Put this `ScreenNode` at the next available `ScreenId`, and return that.
+    pub(super) fn insert_node(
+        &mut self,
+        node: ScreenNode,
+    ) -> ScreenId {
+        let id: ScreenId = self.next_id();
+        if let Some(slot) = self.screens.get_mut(*id) {
+            *slot = Some(node);
+        } else {
+            self.screens.push(Some(node));
+        }
+        id
     }
 
     /// Ensure that the passed ID is valid.
@@ -57,8 +109,7 @@ impl ScreenManager {
         &self,
         id: ScreenId,
     ) -> Result<()> {
-        self.screens
-            .get(*id)
+        self.get_screen_node(id)
             .ok_or_eyre(format!(
                 "Passed ID ({}) doesn't point to a `ScreenNode`.",
                 *id
@@ -71,7 +122,7 @@ impl ScreenManager {
         &self,
         id: ScreenId,
     ) -> Option<&ScreenNode> {
-        self.screens.get(*id)
+        self.screens.get(*id).and_then(Option::as_ref)
     }
 
     /// Get the `Node` of the given `ScreenId` as a mutable reference, if one.
@@ -79,7 +130,7 @@ impl ScreenManager {
         &mut self,
         id: ScreenId,
     ) -> Option<&mut ScreenNode> {
-        self.screens.get_mut(*id)
+        self.screens.get_mut(*id).and_then(Option::as_mut)
     }
 
     /// Get the `ScreenNode` of the current `Screen`.
@@ -87,7 +138,7 @@ impl ScreenManager {
     /// # Panics
     /// If the `current_id` doesn't point to a `ScreenNode`.
     pub fn get_current_screen_node(&self) -> &ScreenNode {
-        self.screens.get(*self.current_id()).unwrap_or_else(|| {
+        self.get_screen_node(self.current_id()).unwrap_or_else(|| {
             panic!(
                 "The `current_id` ({}) doesn't point to a `ScreenNode`.",
                 *self.current_id()
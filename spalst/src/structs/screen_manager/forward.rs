@@ -0,0 +1,37 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{NavigationDirection, Request},
+    structs::{ScreenId, ScreenManager},
+};
+use color_eyre::eyre::{OptionExt as _, Result};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl ScreenManager {
+    /// Go forward to the screen that was last gone back from.
+    ///
+    /// The current screen is paused, and the next one is entered again, since it was exited when
+    /// it was gone back from (see `Screen`). The `Request`s they make are pushed to `requests`. Going back leads to the current screen afterwards.
+    ///
+    /// # Errors
+    /// If there's no screen to go forward to.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn forward(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) -> Result<ScreenId> {
+        let id: ScreenId = self
+            .forward_stack
+            .pop()
+            .ok_or_eyre("There's no screen to go forward to.")?;
+        self.pause_current(requests);
+        let from_id: ScreenId = self.current_id;
+        self.back_stack.push(from_id);
+        self.current_id = id;
+        self.start_transition(from_id, NavigationDirection::Forward);
+        self.enter(id, requests);
+        Ok(self.current_id)
+    }
+}
@@ -0,0 +1,63 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::Request,
+    structs::{ScreenId, ScreenManager},
+};
+
+impl ScreenManager {
+    /// Call `Screen::on_pause()` on the current screen, which is about to stop being the current
+    /// one.
+    pub(super) fn pause_current(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) {
+        if let Some(node) = self.get_screen_node_mut(self.current_id) {
+            node.screen.on_pause(requests);
+        }
+    }
+
+    /// Call `Screen::on_exit()` on the screen this `ScreenId` points to, which is being gone back
+    /// from.
+    pub(super) fn exit(
+        &mut self,
+        id: ScreenId,
+        requests: &mut Vec<Request>,
+    ) {
+        if let Some(node) = self.get_screen_node_mut(id) {
+            node.screen.on_exit(requests);
+        }
+    }
+
+    /// Call `Screen::on_enter()` on the screen this `ScreenId` points to, which just became the
+    /// current one again after it was exited.
+    pub(super) fn enter(
+        &mut self,
+        id: ScreenId,
+        requests: &mut Vec<Request>,
+    ) {
+        if let Some(node) = self.get_screen_node_mut(id) {
+            node.entered = true;
+            node.screen.on_enter(requests);
+        }
+    }
+
+    /// Call `Screen::on_enter()` on the screen this `ScreenId` points to, which just became the
+    /// current one, or `Screen::on_resume()` if it was entered before.
+    pub(super) fn show(
+        &mut self,
+        id: ScreenId,
+        requests: &mut Vec<Request>,
+    ) {
+        let Some(node) = self.get_screen_node_mut(id) else {
+            return;
+        };
+        if node.entered {
+            node.screen.on_resume(requests);
+        } else {
+            node.entered = true;
+            node.screen.on_enter(requests);
+        }
+    }
+}
@@ -2,10 +2,19 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 mod back;
+mod breadcrumbs;
+mod collect_garbage;
 mod def;
+mod forward;
+mod lifecycle;
 mod new;
 mod new_screen;
+mod overlays;
 mod render;
 mod select_screen;
+mod shortcuts;
+#[cfg(test)]
+mod tests;
+mod transition;
 
 pub use def::ScreenManager;
@@ -1,7 +1,7 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::structs::ScreenManager;
+use crate::structs::{ScreenManager, TransitionSettings};
 
 impl ScreenManager {
     /// Construct a new `ScreenManager` with a root `Screen`.
@@ -10,7 +10,13 @@ impl ScreenManager {
             screens: Vec::new(),
             root_id: usize::MAX.into(),
             current_id: usize::MAX.into(),
-            path_from_root: Vec::new(),
+            back_stack: Vec::new(),
+            forward_stack: Vec::new(),
+            overlays: Vec::new(),
+            transition_settings: TransitionSettings::default(),
+            transition: None,
+            transitions_started: 0,
+            show_breadcrumbs: true,
         };
 
         instance.add_root_screen();
@@ -2,16 +2,21 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 use crate::{
-    structs::{EmptyScreen, ScreenId, ScreenManager},
+    enums::Request,
+    structs::{EmptyScreen, ScreenId, ScreenManager, ScreenNode},
     traits::Screen,
 };
 use color_eyre::eyre::{OptionExt as _, Result};
+use core::any::{Any, TypeId};
 #[cfg(feature = "logging")]
 use tracing::instrument;
 
 impl ScreenManager {
     /// Add a new screen as a child of the screen the passed `ScreenId` points to.
     ///
+    /// It is kept until it is shown, and reclaimed like every other screen afterwards (see
+    /// `self.collect_garbage()`).
+    ///
     /// # Errors
     /// If the passed `ScreenId` doesn't point to a `Screen`.
     #[cfg_attr(feature = "logging", instrument)]
@@ -20,10 +25,10 @@ impl ScreenManager {
         parent_id: ScreenId,
         screen: Box<dyn Screen>,
     ) -> Result<ScreenId> {
-        let id: ScreenId = self.next_id();
-
-        self.screens.push(screen.into());
-        self.path_from_root.push(id);
+        self.ensure_valid_id(parent_id)?;
+        let mut node: ScreenNode = screen.into();
+        node.add_parent(parent_id);
+        let id: ScreenId = self.insert_node(node);
 
         self.get_screen_node_mut(parent_id)
             .ok_or_eyre(format!(
@@ -37,26 +42,67 @@ impl ScreenManager {
 
     /// Add a new screen as a child of the screen the passed `ScreenId` points to, and display it.
     ///
+    /// The current screen is paused, and the new one is entered (see `Screen`). The `Request`s
+    /// they make are pushed to `requests`. The history is updated like in `self.select_screen()`.
+    ///
     /// # Errors
     /// If the call to `self.add_screen()` fails.
-    #[cfg_attr(feature = "logging", instrument)]
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
     pub fn add_select_screen(
         &mut self,
         parent_id: ScreenId,
         screen: Box<dyn Screen>,
+        requests: &mut Vec<Request>,
     ) -> Result<ScreenId> {
         let id: ScreenId = self.add_screen(parent_id, screen)?;
-        self.current_id = id;
+        self.navigate(id, requests);
         Ok(id)
     }
 
+    /// Select the child of the screen the passed `ScreenId` points to that has the same type as
+    /// the passed screen, if one, instead of adding a duplicate of it. Otherwise, add the passed
+    /// screen and select it (see `self.add_select_screen()`).
+    ///
+    /// A reused screen keeps its state, and the passed one is dropped.
+    ///
+    /// # Errors
+    /// If the passed `ScreenId` doesn't point to a `Screen`, or if the call to
+    /// `self.add_select_screen()` fails.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn add_or_focus_screen(
+        &mut self,
+        parent_id: ScreenId,
+        screen: Box<dyn Screen>,
+        requests: &mut Vec<Request>,
+    ) -> Result<ScreenId> {
+        let new_screen: &dyn Any = screen.as_ref();
+        let type_id: TypeId = new_screen.type_id();
+        let existing: Option<ScreenId> = self
+            .get_screen_node(parent_id)
+            .ok_or_eyre(format!(
+                "Passed parent ID with value {} doesn't point to a screen node.",
+                *parent_id,
+            ))?
+            .children()
+            .iter()
+            .copied()
+            .find(|id: &ScreenId| {
+                self.get_screen_node(*id)
+                    .is_some_and(|node: &ScreenNode| node.screen_type_id() == type_id)
+            });
+        if let Some(id) = existing {
+            self.select_screen(id, requests)?;
+            Ok(id)
+        } else {
+            self.add_select_screen(parent_id, screen, requests)
+        }
+    }
+
     /// Add a root `Screen`.
     pub(super) fn add_root_screen(&mut self) {
-        let id: ScreenId = self.next_id();
         let empty_screen: Box<dyn Screen> = Box::new(EmptyScreen);
-        self.screens.push(empty_screen.into());
+        let id: ScreenId = self.insert_node(empty_screen.into());
         self.root_id = id;
         self.current_id = id;
-        self.path_from_root.push(id);
     }
 }
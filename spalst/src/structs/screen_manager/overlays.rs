@@ -0,0 +1,70 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::Request,
+    structs::{Overlay, ScreenManager},
+    traits::Screen,
+};
+use color_eyre::eyre::{OptionExt as _, Result};
+use core::any::Any;
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl ScreenManager {
+    /// Whether a modal overlay is open, so that the current `Screen` can't be used.
+    pub fn has_modal_overlay(&self) -> bool {
+        self.overlays.iter().any(|overlay: &Overlay| overlay.modal)
+    }
+
+    /// Open an overlay with this screen over the current `Screen`, on top of every other overlay.
+    ///
+    /// The overlay is entered (see `Screen`), and if it is `modal`, the current `Screen` is paused.
+    /// The `Request`s they make are pushed to `requests`.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn open_overlay(
+        &mut self,
+        mut screen: Box<dyn Screen>,
+        modal: bool,
+        requests: &mut Vec<Request>,
+    ) {
+        if modal {
+            self.pause_current(requests);
+        }
+        screen.on_enter(requests);
+        self.overlays
+            .push(Overlay::new(screen, modal, self.current_id));
+    }
+
+    /// Close the top overlay, and hand `result` (if any) to the `Screen` it was opened over (see
+    /// `Screen::on_overlay_result()`).
+    ///
+    /// The overlay is exited (see `Screen`), and if it was `modal` and the `Screen` it was opened
+    /// over is still the current one, that is resumed. The `Request`s they make are pushed to
+    /// `requests`.
+    ///
+    /// # Errors
+    /// If there's no overlay to close.
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
+    pub fn close_overlay(
+        &mut self,
+        result: Option<Box<dyn Any + Send + Sync>>,
+        requests: &mut Vec<Request>,
+    ) -> Result<()> {
+        let mut overlay: Overlay = self
+            .overlays
+            .pop()
+            .ok_or_eyre("There's no overlay to close.")?;
+        overlay.screen.on_exit(requests);
+        let resume: bool = overlay.modal && overlay.owner_id == self.current_id;
+        if let Some(node) = self.get_screen_node_mut(overlay.owner_id) {
+            if let Some(result) = result {
+                node.screen.on_overlay_result(result, requests);
+            }
+            if resume {
+                node.screen.on_resume(requests);
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,29 +2,207 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 use crate::{
-    enums::Request,
-    structs::{App, ScreenManager},
+    enums::{NavigationDirection, Request, ScreenManagerRequest, TransitionKind},
+    structs::{AppState, EmptyScreen, Overlay, ScreenId, ScreenManager, ScreenNode, Transition},
+    traits::Screen,
 };
 use color_eyre::eyre::{OptionExt as _, Result};
-use egui::Ui;
+use core::mem;
+use egui::{InnerResponse, Rect, Ui, UiBuilder, Vec2, lerp};
 #[cfg(feature = "logging")]
 use tracing::instrument;
 
 impl ScreenManager {
-    /// Render the screen by calling `ui()` (provided by `Screen`) on the current screen.
-    #[cfg_attr(feature = "logging", instrument(skip(ui)))]
+    /// Render the screen by calling `ui()` (provided by `Screen`) on the current screen of the
+    /// `ScreenManager` of `state`, and on its overlays.
+    ///
+    /// The overlays are rendered first, from the top one down, so that the top one gets to use
+    /// (and consume) the input before anything else. Only the `Request`s of the top overlay are
+    /// kept, and the other overlays are disabled. While a modal overlay is open, the current
+    /// screen (and the breadcrumb bar, if shown) is disabled too, and its `Request`s are dropped.
+    ///
+    /// While a `Transition` is in flight, the `Screen` it switches away from is rendered as well
+    /// (see `ScreenManager::render_transition()`).
+    ///
+    /// The screen needs to be borrowed mutably while the rest of `state` is borrowed immutably, so
+    /// it is taken out of its `ScreenNode` for the duration of the call, and an `EmptyScreen` is
+    /// left in its place. The overlays are taken out the same way.
+    ///
+    /// # Errors
+    /// If the `current_id`, or the `ScreenId` the `Transition` in flight switches away from,
+    /// doesn't point to a `ScreenNode`.
+    #[cfg_attr(feature = "logging", instrument(skip(ui, state)))]
     pub fn render(
-        &self,
         ui: &mut Ui,
-        app: &App,
+        state: &mut AppState,
     ) -> Result<Vec<Request>> {
-        Ok(self
-            .get_screen_node(self.current_id)
-            .ok_or_eyre(format!(
-                "The current_id ({}) doesn't point to a `Node`",
-                *self.current_id
-            ))?
-            .screen
-            .ui(ui, app))
+        let mut requests: Vec<Request> = Self::render_overlays(ui, state);
+        let modal: bool = state.screen_manager.has_modal_overlay();
+
+        let mut screen_requests: Vec<Request> = Vec::new();
+        if state.screen_manager.show_breadcrumbs {
+            let crumb_request: InnerResponse<Option<ScreenManagerRequest>> = ui.add_enabled_ui(!modal, |ui: &mut Ui| {
+                state.screen_manager.breadcrumbs_ui(ui)
+            });
+            screen_requests.extend(crumb_request.inner.map(Request::ScreenManager));
+        }
+        if let Some((transition, progress)) = Self::advance_transition(ui, state) {
+            screen_requests.extend(Self::render_transition(
+                ui, state, transition, progress, !modal,
+            )?);
+        } else {
+            let id: ScreenId = state.screen_manager.current_id;
+            screen_requests.extend(
+                ui.add_enabled_ui(!modal, |ui: &mut Ui| Self::render_screen(ui, state, id))
+                    .inner?,
+            );
+        }
+        if !modal {
+            requests.extend(screen_requests);
+        }
+        Ok(requests)
+    }
+
+    /// Render the screen this `ScreenId` points to, and return its `Request`s.
+    ///
+    /// # Errors
+    /// If the `ScreenId` doesn't point to a `ScreenNode`.
+    fn render_screen(
+        ui: &mut Ui,
+        state: &mut AppState,
+        id: ScreenId,
+    ) -> Result<Vec<Request>> {
+        let mut screen: Box<dyn Screen> = mem::replace(
+            &mut state.screen_manager.existing_screen_node_mut(id)?.screen,
+            Box::new(EmptyScreen),
+        );
+        let requests: Vec<Request> = screen.ui(ui, state);
+        state.screen_manager.existing_screen_node_mut(id)?.screen = screen;
+        Ok(requests)
+    }
+
+    /// Advance the `Transition` in flight, if any, and return it along with how far it is. It is
+    /// dropped once it finishes, or if the `Screen` it switches away from is gone.
+    fn advance_transition(
+        ui: &Ui,
+        state: &mut AppState,
+    ) -> Option<(Transition, f32)> {
+        let screen_manager: &mut Self = &mut state.screen_manager;
+        let mut transition: Transition = screen_manager.transition?;
+        let progress: f32 = transition.progress(ui.ctx());
+        screen_manager.transition = (progress < 1.0 && screen_manager.get_screen_node(transition.from_id).is_some()).then_some(transition);
+        screen_manager
+            .transition
+            .map(|advanced: Transition| (advanced, progress))
+    }
+
+    /// Render both `Screen`s of this `Transition`, this far into it. Only the `Request`s of the
+    /// current one are returned, and the other one is disabled.
+    ///
+    /// Sliding moves the current `Screen` in from the right and the other one out to the left when
+    /// going forward, and the other way around when going back. Fading fades the other `Screen`
+    /// out while the current one fades in.
+    ///
+    /// # Errors
+    /// If either `ScreenId` doesn't point to a `ScreenNode`.
+    fn render_transition(
+        ui: &mut Ui,
+        state: &mut AppState,
+        transition: Transition,
+        progress: f32,
+        enabled: bool,
+    ) -> Result<Vec<Request>> {
+        let rect: Rect = ui.available_rect_before_wrap();
+        let shift: Vec2 = match transition.direction {
+            NavigationDirection::Forward => Vec2::new(rect.width(), 0.0),
+            NavigationDirection::Back => -Vec2::new(rect.width(), 0.0),
+        };
+        let (from_offset, to_offset, from_opacity, to_opacity): (Vec2, Vec2, f32, f32) = match transition.kind {
+            TransitionKind::Slide => (
+                lerp(Vec2::ZERO..=-shift, progress),
+                lerp(shift..=Vec2::ZERO, progress),
+                1.0,
+                1.0,
+            ),
+            TransitionKind::Fade => (Vec2::ZERO, Vec2::ZERO, lerp(1.0..=0.0, progress), progress),
+            TransitionKind::None => (Vec2::ZERO, Vec2::ZERO, 0.0, 1.0),
+        };
+
+        let _: Vec<Request> = Self::render_screen_in(
+            ui,
+            state,
+            transition.from_id,
+            rect.translate(from_offset),
+            from_opacity,
+            false,
+        )?;
+        let id: ScreenId = state.screen_manager.current_id;
+        Self::render_screen_in(
+            ui,
+            state,
+            id,
+            rect.translate(to_offset),
+            to_opacity,
+            enabled,
+        )
+    }
+
+    /// Render the screen this `ScreenId` points to within this `Rect` (clipped to the `Ui`), with
+    /// this opacity, and return its `Request`s.
+    ///
+    /// # Errors
+    /// If the `ScreenId` doesn't point to a `ScreenNode`.
+    fn render_screen_in(
+        ui: &mut Ui,
+        state: &mut AppState,
+        id: ScreenId,
+        rect: Rect,
+        opacity: f32,
+        enabled: bool,
+    ) -> Result<Vec<Request>> {
+        let clip_rect: Rect = ui.clip_rect().intersect(ui.available_rect_before_wrap());
+        ui.scope_builder(UiBuilder::new().max_rect(rect), |ui: &mut Ui| {
+            ui.set_clip_rect(clip_rect);
+            ui.multiply_opacity(opacity);
+            if !enabled {
+                ui.disable();
+            }
+            Self::render_screen(ui, state, id)
+        })
+        .inner
+    }
+
+    /// Render the overlays of the `ScreenManager` of `state` (see `Overlay::show()`), and return
+    /// the `Request`s of the top one.
+    fn render_overlays(
+        ui: &Ui,
+        state: &mut AppState,
+    ) -> Vec<Request> {
+        let mut overlays: Vec<Overlay> = mem::take(&mut state.screen_manager.overlays);
+        let top: Option<usize> = overlays.len().checked_sub(1);
+        let mut requests: Vec<Request> = Vec::new();
+        for (index, overlay) in overlays.iter_mut().enumerate().rev() {
+            let is_top: bool = top == Some(index);
+            let overlay_requests: Vec<Request> = overlay.show(ui, state, index, is_top);
+            if is_top {
+                requests = overlay_requests;
+            }
+        }
+        state.screen_manager.overlays = overlays;
+        requests
+    }
+
+    /// Get the `ScreenNode` of the given `ScreenId` as a mutable reference.
+    ///
+    /// # Errors
+    /// If the `ScreenId` doesn't point to a `ScreenNode`.
+    fn existing_screen_node_mut(
+        &mut self,
+        id: ScreenId,
+    ) -> Result<&mut ScreenNode> {
+        self.get_screen_node_mut(id).ok_or_eyre(format!(
+            "The `ScreenId` ({}) doesn't point to a `Node`",
+            *id
+        ))
     }
 }
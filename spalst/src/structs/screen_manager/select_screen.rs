@@ -1,7 +1,10 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::structs::{ScreenId, ScreenManager};
+use crate::{
+    enums::{NavigationDirection, Request},
+    structs::{ScreenId, ScreenManager},
+};
 use color_eyre::eyre::Result;
 #[cfg(feature = "logging")]
 use tracing::instrument;
@@ -9,15 +12,40 @@ use tracing::instrument;
 impl ScreenManager {
     /// Select the `Screen` with the passed `ID`.
     ///
+    /// Unless it already is the current one, the current `Screen` is paused, and the selected one
+    /// is entered or resumed (see `Screen`). The `Request`s they make are pushed to `requests`.
+    /// Going back leads to the current `Screen` afterwards, and there's nothing to go forward to.
+    ///
     /// # Errors
     /// If the passed ID doesn't point to a `ScreenNode`.
-    #[cfg_attr(feature = "logging", instrument)]
+    #[cfg_attr(feature = "logging", instrument(skip(requests)))]
     pub fn select_screen(
         &mut self,
         id: ScreenId,
+        requests: &mut Vec<Request>,
     ) -> Result<()> {
         self.ensure_valid_id(id)?;
-        self.current_id = id;
+        if id != self.current_id {
+            self.navigate(id, requests);
+        }
         Ok(())
     }
+
+    /// Make the `Screen` this valid `ScreenId` points to the current one, without going back or
+    /// forward. The screens that can't be gone forward to anymore are reclaimed, unless they are
+    /// pinned (see `self.collect_garbage()`).
+    pub(super) fn navigate(
+        &mut self,
+        id: ScreenId,
+        requests: &mut Vec<Request>,
+    ) {
+        self.pause_current(requests);
+        let from_id: ScreenId = self.current_id;
+        self.back_stack.push(from_id);
+        self.forward_stack.clear();
+        self.current_id = id;
+        self.start_transition(from_id, NavigationDirection::Forward);
+        self.show(id, requests);
+        let _: usize = self.collect_garbage();
+    }
 }
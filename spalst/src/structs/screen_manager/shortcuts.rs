@@ -0,0 +1,55 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::ScreenManagerRequest,
+    structs::{MainMenuScreen, ScreenId, ScreenManager, ScreenNode},
+};
+use core::any::TypeId;
+use egui::{InputState, Key, Modifiers, PointerButton};
+
+impl ScreenManager {
+    /// Get the `ScreenManagerRequest` to go back or forward, if the mouse back or forward button
+    /// was pressed, or Alt+Left or Alt+Right. Alt+Home goes back to the nearest `MainMenuScreen`.
+    /// The keys are consumed.
+    ///
+    /// Nothing is allowed while a modal overlay is open, or from a gameplay screen (see
+    /// `Screen::is_gameplay()`), since playing has to be started and stopped through the game
+    /// itself. For the same reason, going forward to a gameplay screen isn't allowed either. Going
+    /// back to the root screen isn't allowed, since it is empty.
+    pub fn history_shortcut(
+        &self,
+        input: &mut InputState,
+    ) -> Option<ScreenManagerRequest> {
+        let back: bool = input.pointer.button_pressed(PointerButton::Extra1) | input.consume_key(Modifiers::ALT, Key::ArrowLeft);
+        let forward: bool = input.pointer.button_pressed(PointerButton::Extra2) | input.consume_key(Modifiers::ALT, Key::ArrowRight);
+        let home: bool = input.consume_key(Modifiers::ALT, Key::Home);
+        let main_menu: TypeId = TypeId::of::<MainMenuScreen>();
+
+        if self.has_modal_overlay() || self.get_current_screen_node().screen.is_gameplay() {
+            None
+        } else if home
+            && self.back_stack.iter().any(|id: &ScreenId| {
+                self.get_screen_node(*id)
+                    .is_some_and(|node: &ScreenNode| node.screen_type_id() == main_menu)
+            })
+        {
+            Some(ScreenManagerRequest::BackToScreenType(main_menu))
+        } else if back
+            && self
+                .previous_id()
+                .is_some_and(|id: ScreenId| id != self.root_id)
+        {
+            Some(ScreenManagerRequest::Back)
+        } else if forward
+            && self
+                .forward_id()
+                .and_then(|id: ScreenId| self.get_screen_node(id))
+                .is_some_and(|node: &ScreenNode| !node.screen.is_gameplay())
+        {
+            Some(ScreenManagerRequest::Forward)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,542 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+//!
+//! Unit tests for `ScreenManager`.
+
+use crate::{
+    enums::{Confirmation, NavigationDirection, PlaythroughRequest, Request, ScreenManagerRequest, TransitionKind},
+    structs::{AppState, ConfirmDialog, GameScreen, MainMenuScreen, ManagePlaythroughsScreen, PauseScreen, ScreenId, ScreenManager, ScreenNode, SettingsScreen, Transition, TransitionSettings},
+    traits::Screen,
+};
+use color_eyre::eyre::Result;
+use core::any::TypeId;
+use egui::{Event, InputState, Key, Modifiers, Ui};
+
+/// Make a `ScreenManager` that went from the root to a `MainMenuScreen` and then to a
+/// `SettingsScreen`. Returns it along with the `ScreenId`s of both.
+fn with_history() -> Result<(ScreenManager, ScreenId, ScreenId)> {
+    let mut screen_manager: ScreenManager = ScreenManager::new();
+    let main_menu: ScreenId = screen_manager.add_select_screen(
+        screen_manager.root_id(),
+        Box::new(MainMenuScreen),
+        &mut Vec::new(),
+    )?;
+    let settings: ScreenId = screen_manager.add_select_screen(main_menu, Box::new(SettingsScreen::new()), &mut Vec::new())?;
+    Ok((screen_manager, main_menu, settings))
+}
+
+/// Adding a screen without selecting it leaves the history alone.
+#[test]
+fn adding_keeps_history() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let _: ScreenId = screen_manager.add_screen(settings, Box::new(SettingsScreen::new()))?;
+    assert_eq!(
+        screen_manager.current_id(),
+        settings,
+        "Adding a screen must not select it."
+    );
+    assert_eq!(
+        screen_manager.previous_id(),
+        Some(main_menu),
+        "Adding a screen must not change where going back leads."
+    );
+    assert_eq!(
+        screen_manager.back(&mut Vec::new())?,
+        main_menu,
+        "Going back must skip screens that were only added."
+    );
+    Ok(())
+}
+
+/// Going back and then forward returns to where the user was.
+#[test]
+fn back_and_forward() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    assert!(
+        screen_manager.forward(&mut Vec::new()).is_err(),
+        "There must be nothing to go forward to before going back."
+    );
+    assert_eq!(
+        screen_manager.back(&mut Vec::new())?,
+        main_menu,
+        "Going back must lead to the previous screen."
+    );
+    assert_eq!(
+        screen_manager.forward_id(),
+        Some(settings),
+        "Going forward must lead to the screen that was gone back from."
+    );
+    assert_eq!(
+        screen_manager.forward(&mut Vec::new())?,
+        settings,
+        "Going forward must lead to the screen that was gone back from."
+    );
+    assert_eq!(
+        screen_manager.previous_id(),
+        Some(main_menu),
+        "Going forward must keep the back history."
+    );
+    assert_eq!(
+        screen_manager.forward_id(),
+        None,
+        "Going forward must use up the forward history."
+    );
+    Ok(())
+}
+
+/// Navigating to a screen without going back or forward clears the forward history.
+#[test]
+fn navigating_clears_forward() -> Result<()> {
+    let (mut screen_manager, main_menu, _): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    screen_manager.select_screen(screen_manager.root_id(), &mut Vec::new())?;
+    assert_eq!(
+        screen_manager.forward_id(),
+        None,
+        "Selecting a screen must clear the forward history."
+    );
+    assert_eq!(
+        screen_manager.previous_id(),
+        Some(main_menu),
+        "Selecting a screen must add the current one to the back history."
+    );
+    screen_manager.select_screen(main_menu, &mut Vec::new())?;
+    assert_eq!(
+        screen_manager.previous_id(),
+        Some(screen_manager.root_id()),
+        "A screen can appear in the back history more than once."
+    );
+    Ok(())
+}
+
+/// Selecting the current screen changes nothing.
+#[test]
+fn selecting_current_screen() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    screen_manager.select_screen(settings, &mut Vec::new())?;
+    assert_eq!(
+        screen_manager.previous_id(),
+        Some(main_menu),
+        "Selecting the current screen must not add it to the back history."
+    );
+    Ok(())
+}
+
+/// Going back several screens at once puts every skipped screen on the forward history, in order.
+#[test]
+fn back_skips_screens() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    assert_eq!(
+        screen_manager.back_steps(2, &mut Vec::new())?,
+        screen_manager.root_id(),
+        "Going back two screens must lead to the root screen."
+    );
+    assert!(
+        screen_manager.back(&mut Vec::new()).is_err(),
+        "There must be nothing to go back to from the root screen."
+    );
+    assert_eq!(
+        screen_manager.forward(&mut Vec::new())?,
+        main_menu,
+        "Going forward must lead to the first skipped screen."
+    );
+    assert_eq!(
+        screen_manager.forward(&mut Vec::new())?,
+        settings,
+        "Going forward again must lead to the screen that was gone back from."
+    );
+    Ok(())
+}
+
+/// Going back to a screen type finds the nearest screen of that type.
+#[test]
+fn back_to_screen_type() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let _: ScreenId = screen_manager.add_select_screen(settings, Box::new(SettingsScreen::new()), &mut Vec::new())?;
+    assert_eq!(
+        screen_manager.back_to_screen_type(TypeId::of::<SettingsScreen>(), &mut Vec::new())?,
+        settings,
+        "Going back to a screen type must stop at the nearest screen of it."
+    );
+    assert_eq!(
+        screen_manager.back_to_screen_type(TypeId::of::<MainMenuScreen>(), &mut Vec::new())?,
+        main_menu,
+        "Going back to a screen type must find screens further back."
+    );
+    assert!(
+        screen_manager
+            .back_to_screen_type(TypeId::of::<GameScreen>(), &mut Vec::new())
+            .is_err(),
+        "Going back to a screen type that isn't in the history must fail."
+    );
+    assert_eq!(
+        screen_manager.current_id(),
+        main_menu,
+        "A failed attempt to go back must not change the current screen."
+    );
+    Ok(())
+}
+
+/// The lifecycle hooks are called when navigating.
+#[test]
+fn hooks_are_called() -> Result<()> {
+    let (mut screen_manager, _, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let game: ScreenId = screen_manager.add_select_screen(settings, Box::new(GameScreen), &mut Vec::new())?;
+    let mut requests: Vec<Request> = Vec::new();
+    screen_manager.open_overlay(Box::new(PauseScreen), true, &mut requests);
+    assert!(
+        matches!(requests.as_slice(), [Request::Autosave]),
+        "Opening a modal overlay over a `GameScreen` must pause it, which autosaves."
+    );
+    requests.clear();
+    screen_manager.close_overlay(None, &mut requests)?;
+    assert!(
+        requests.is_empty(),
+        "Resuming a `GameScreen` must not make requests."
+    );
+    assert_eq!(
+        screen_manager.current_id(),
+        game,
+        "Overlays must not change the current screen."
+    );
+    let _: ScreenId = screen_manager.back(&mut requests)?;
+    assert!(
+        matches!(requests.as_slice(), [Request::Autosave]),
+        "Exiting a `GameScreen` must autosave."
+    );
+    Ok(())
+}
+
+/// A `Screen` that tells which of `Screen::on_enter()` and `Screen::on_resume()` was called, by
+/// making a `PlaythroughRequest::Refresh` or a `Request::Autosave` respectively.
+#[derive(Debug)]
+struct HookScreen;
+
+impl Screen for HookScreen {
+    fn title(&self) -> String {
+        "Hooks".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        _ui: &mut Ui,
+        _state: &AppState,
+    ) -> Vec<Request> {
+        Vec::new()
+    }
+
+    fn on_enter(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) {
+        requests.push(Request::Playthrough(PlaythroughRequest::Refresh));
+    }
+
+    fn on_resume(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) {
+        requests.push(Request::Autosave);
+    }
+}
+
+/// A screen that was exited is entered again when it is gone forward to, not resumed.
+#[test]
+fn forward_enters_again() -> Result<()> {
+    let (mut screen_manager, _, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let _: ScreenId = screen_manager.add_select_screen(settings, Box::new(HookScreen), &mut Vec::new())?;
+    let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    let mut requests: Vec<Request> = Vec::new();
+    let _: ScreenId = screen_manager.forward(&mut requests)?;
+    assert!(
+        matches!(
+            requests.as_slice(),
+            [Request::Playthrough(PlaythroughRequest::Refresh)]
+        ),
+        "Going forward to a screen that was exited must enter it."
+    );
+    Ok(())
+}
+
+/// Count the `ScreenNode`s that weren't reclaimed.
+fn live_screens(screen_manager: &ScreenManager) -> usize {
+    screen_manager.screens.iter().flatten().count()
+}
+
+/// Screens that can't be gone back or forward to anymore are reclaimed, and their `ScreenId`s
+/// reused.
+#[test]
+fn unreachable_screens_are_reclaimed() -> Result<()> {
+    let (mut screen_manager, main_menu, _): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    for _ in 0..100_u8 {
+        let _: ScreenId = screen_manager.add_select_screen(main_menu, Box::new(SettingsScreen::new()), &mut Vec::new())?;
+        let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    }
+    assert_eq!(
+        live_screens(&screen_manager),
+        3,
+        "Only the root, the main menu and the last settings screen must be kept."
+    );
+    assert!(
+        screen_manager.screens.len() <= 4,
+        "The `ScreenId`s of reclaimed screens must be reused."
+    );
+    let settings: ScreenId = screen_manager.forward(&mut Vec::new())?;
+    assert!(
+        screen_manager
+            .get_screen_node(main_menu)
+            .is_some_and(|node: &ScreenNode| node.children() == [settings]),
+        "Reclaimed screens must be removed from the children of their parents."
+    );
+    Ok(())
+}
+
+/// Pinned screens and screens that were never shown aren't reclaimed.
+#[test]
+fn pinned_screens_are_kept() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let hidden: ScreenId = screen_manager.add_screen(main_menu, Box::new(SettingsScreen::new()))?;
+    screen_manager.set_pinned(settings, true)?;
+    let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    screen_manager.select_screen(screen_manager.root_id(), &mut Vec::new())?;
+    assert!(
+        screen_manager.get_screen_node(settings).is_some(),
+        "Pinned screens must not be reclaimed."
+    );
+    assert!(
+        screen_manager.get_screen_node(hidden).is_some(),
+        "Screens that were never shown must not be reclaimed."
+    );
+    screen_manager.set_pinned(settings, false)?;
+    assert_eq!(
+        screen_manager.collect_garbage(),
+        1,
+        "Unpinned screens must be reclaimed."
+    );
+    Ok(())
+}
+
+/// Going back several screens at once exits the skipped ones too, so that nothing is reclaimed
+/// without being exited first.
+#[test]
+fn skipped_screens_are_exited() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let game: ScreenId = screen_manager.add_select_screen(settings, Box::new(GameScreen), &mut Vec::new())?;
+    let _: ScreenId = screen_manager.add_select_screen(game, Box::new(SettingsScreen::new()), &mut Vec::new())?;
+
+    let mut requests: Vec<Request> = Vec::new();
+    let _: ScreenId = screen_manager.back_steps(3, &mut requests)?;
+    assert!(
+        requests
+            .iter()
+            .any(|request: &Request| matches!(*request, Request::Autosave)),
+        "The skipped game must be exited."
+    );
+    screen_manager.select_screen(settings, &mut Vec::new())?;
+    assert!(
+        screen_manager.get_screen_node(game).is_none(),
+        "The skipped game must be reclaimed once it is off the history."
+    );
+    assert_eq!(
+        screen_manager.current_id(),
+        settings,
+        "Selecting must lead to the selected screen."
+    );
+    let _: ScreenId = main_menu;
+    Ok(())
+}
+
+/// Adding or focusing a screen reuses a child of the same type.
+#[test]
+fn add_or_focus_reuses_screens() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    assert_eq!(
+        screen_manager.add_or_focus_screen(main_menu, Box::new(SettingsScreen::new()), &mut Vec::new())?,
+        settings,
+        "A live child of the same type must be reused."
+    );
+    assert_eq!(
+        screen_manager.previous_id(),
+        Some(main_menu),
+        "Reusing a screen must navigate to it."
+    );
+    let game: ScreenId = screen_manager.add_or_focus_screen(settings, Box::new(GameScreen), &mut Vec::new())?;
+    assert_ne!(
+        game, settings,
+        "A screen must be added if no child has its type."
+    );
+    Ok(())
+}
+
+/// Closing an overlay hands its result to the screen it was opened over.
+#[test]
+fn overlay_result_reaches_owner() -> Result<()> {
+    let (mut screen_manager, main_menu, _): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let manage: ScreenId = screen_manager.add_select_screen(
+        main_menu,
+        Box::new(ManagePlaythroughsScreen::new()),
+        &mut Vec::new(),
+    )?;
+    let dialog: ConfirmDialog<PlaythroughRequest> = ConfirmDialog::new(
+        "Delete".to_owned(),
+        String::new(),
+        "Delete".to_owned(),
+        PlaythroughRequest::Delete("test".to_owned()),
+    );
+    screen_manager.open_overlay(Box::new(dialog), true, &mut Vec::new());
+    assert!(
+        screen_manager.has_modal_overlay(),
+        "The dialog must be open."
+    );
+
+    let mut requests: Vec<Request> = Vec::new();
+    screen_manager.close_overlay(
+        Some(Box::new(Confirmation::Confirmed(
+            PlaythroughRequest::Delete("test".to_owned()),
+        ))),
+        &mut requests,
+    )?;
+    assert!(
+        matches!(
+            requests.as_slice(),
+            [Request::Playthrough(PlaythroughRequest::Delete(name))] if name == "test"
+        ),
+        "The screen the dialog was opened over must act on the confirmation."
+    );
+    assert_eq!(
+        screen_manager.current_id(),
+        manage,
+        "Overlays must not change the current screen."
+    );
+    assert!(
+        screen_manager.close_overlay(None, &mut Vec::new()).is_err(),
+        "Closing an overlay when none is open must fail."
+    );
+    Ok(())
+}
+
+/// Switching screens starts a `Transition` in the direction of the switch, unless motion is
+/// reduced.
+#[test]
+fn transitions_follow_navigation() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    assert!(
+        screen_manager
+            .transition
+            .is_some_and(|transition: Transition| { transition.from_id == main_menu && transition.direction == NavigationDirection::Forward }),
+        "Navigating must start a forward transition from the previous screen."
+    );
+    let _: ScreenId = screen_manager.back(&mut Vec::new())?;
+    assert!(
+        screen_manager
+            .transition
+            .is_some_and(|transition: Transition| { transition.from_id == settings && transition.direction == NavigationDirection::Back }),
+        "Going back must start a backward transition from the screen that was left."
+    );
+
+    screen_manager.set_transition_settings(TransitionSettings::new(TransitionKind::Slide, true));
+    assert!(
+        screen_manager.transition.is_none(),
+        "Reducing motion must finish the transition in flight."
+    );
+    let _: ScreenId = screen_manager.forward(&mut Vec::new())?;
+    assert!(
+        screen_manager.transition.is_none(),
+        "No transition may start while motion is reduced."
+    );
+    Ok(())
+}
+
+/// The breadcrumbs follow the history, and going back to one of them goes back that many steps.
+#[test]
+fn breadcrumbs_follow_history() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let game: ScreenId = screen_manager.add_select_screen(settings, Box::new(GameScreen), &mut Vec::new())?;
+    assert_eq!(
+        screen_manager.breadcrumbs(),
+        vec![(main_menu, 2), (settings, 1), (game, 0)],
+        "The breadcrumbs must be the history without the root, with how far back each is."
+    );
+    assert_eq!(
+        screen_manager.window_title(),
+        "Game - Spalst",
+        "The window title must follow the current screen."
+    );
+    assert_eq!(
+        screen_manager.back_steps(2, &mut Vec::new())?,
+        main_menu,
+        "Going back two steps must lead to the first breadcrumb."
+    );
+    assert_eq!(
+        screen_manager.forward_id(),
+        Some(settings),
+        "Going back several steps must keep them to go forward to."
+    );
+    assert!(
+        screen_manager.back_steps(3, &mut Vec::new()).is_err(),
+        "Going back further than the history must fail."
+    );
+    Ok(())
+}
+
+/// The breadcrumbs can't be used to leave the game, since that wouldn't stop playing.
+#[test]
+fn breadcrumbs_keep_game() -> Result<()> {
+    let (mut screen_manager, _, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    assert!(
+        matches!(
+            screen_manager.breadcrumb_request(1),
+            Some(ScreenManagerRequest::BackSteps(1))
+        ),
+        "Breadcrumbs must go back outside of the game."
+    );
+    assert!(
+        screen_manager.breadcrumb_request(0).is_none(),
+        "The current screen must not be clickable."
+    );
+    let _: ScreenId = screen_manager.add_select_screen(settings, Box::new(GameScreen), &mut Vec::new())?;
+    assert!(
+        (0..=2).all(|steps: usize| screen_manager.breadcrumb_request(steps).is_none()),
+        "No breadcrumb may leave the game."
+    );
+    Ok(())
+}
+/// Alt+Home goes back to the nearest main menu, but not out of the game.
+#[test]
+fn home_shortcut() -> Result<()> {
+    let (mut screen_manager, main_menu, settings): (ScreenManager, ScreenId, ScreenId) = with_history()?;
+    let press_home = |manager: &ScreenManager| -> Option<ScreenManagerRequest> {
+        let mut input: InputState = InputState::default();
+        input.events.push(Event::Key {
+            key: Key::Home,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::ALT,
+        });
+        manager.history_shortcut(&mut input)
+    };
+    assert!(
+        matches!(press_home(&screen_manager), Some(ScreenManagerRequest::BackToScreenType(type_id)) if type_id == TypeId::of::<MainMenuScreen>()),
+        "Alt+Home must go back to the main menu."
+    );
+
+    let _: ScreenId = screen_manager.add_select_screen(settings, Box::new(GameScreen), &mut Vec::new())?;
+    assert!(
+        press_home(&screen_manager).is_none(),
+        "Alt+Home must not leave the game."
+    );
+    let _: ScreenId = screen_manager.back_to_screen_type(TypeId::of::<MainMenuScreen>(), &mut Vec::new())?;
+    assert!(
+        press_home(&screen_manager).is_none(),
+        "Alt+Home must do nothing without a main menu to go back to."
+    );
+    assert_eq!(
+        screen_manager.current_id(),
+        main_menu,
+        "Going back to the main menu must lead to it."
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{NavigationDirection, TransitionKind},
+    structs::{ScreenId, ScreenManager, Transition, TransitionSettings},
+};
+#[cfg(feature = "logging")]
+use tracing::instrument;
+
+impl ScreenManager {
+    /// Returns how switching `Screen`s is animated.
+    pub const fn transition_settings(&self) -> TransitionSettings {
+        self.transition_settings
+    }
+
+    /// Change how switching `Screen`s is animated. The `Transition` in flight (if any) is
+    /// finished right away if transitions are turned off.
+    #[cfg_attr(feature = "logging", instrument)]
+    pub fn set_transition_settings(
+        &mut self,
+        settings: TransitionSettings,
+    ) {
+        self.transition_settings = settings;
+        if settings.effective_kind() == TransitionKind::None {
+            self.transition = None;
+        }
+    }
+
+    /// Finish the `Transition` in flight right away, if any.
+    pub const fn skip_transition(&mut self) {
+        self.transition = None;
+    }
+
+    /// Start animating the switch from the `Screen` this `ScreenId` points to, to the current one.
+    /// Replaces the `Transition` in flight, if any.
+    pub(super) fn start_transition(
+        &mut self,
+        from_id: ScreenId,
+        direction: NavigationDirection,
+    ) {
+        let kind: TransitionKind = self.transition_settings.effective_kind();
+        self.transition = (kind != TransitionKind::None && from_id != self.current_id).then(|| Transition::new(from_id, kind, direction, self.transitions_started));
+        self.transitions_started = self.transitions_started.wrapping_add(1);
+    }
+}
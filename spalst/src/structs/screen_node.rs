@@ -2,6 +2,7 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 use crate::{structs::ScreenId, traits::Screen};
+use core::any::{Any, TypeId};
 
 /// A node for a `Screen` in `ScreenManager`.
 #[derive(Debug)]
@@ -12,9 +13,20 @@ pub struct ScreenNode {
     parents: Vec<ScreenId>,
     /// The children of the node.
     children: Vec<ScreenId>,
+    /// Whether `Screen::on_enter()` was called on the screen.
+    pub entered: bool,
+    /// Whether the node is kept even when it isn't reachable (see
+    /// `ScreenManager::collect_garbage()`).
+    pub pinned: bool,
 }
 
 impl ScreenNode {
+    /// Get the `TypeId` of the type of the screen.
+    pub fn screen_type_id(&self) -> TypeId {
+        let screen: &dyn Any = self.screen.as_ref();
+        screen.type_id()
+    }
+
     /// Add this `ScreenId` as a child.
     pub fn add_child(
         &mut self,
@@ -23,6 +35,21 @@ impl ScreenNode {
         self.children.push(child_id);
     }
 
+    /// Returns the `ScreenId`s of the children.
+    pub fn children(&self) -> &[ScreenId] {
+        &self.children
+    }
+
+    /// Remove these `ScreenId`s from the parents and children, since the nodes they pointed to
+    /// were removed.
+    pub fn forget(
+        &mut self,
+        ids: &[ScreenId],
+    ) {
+        self.parents.retain(|id: &ScreenId| !ids.contains(id));
+        self.children.retain(|id: &ScreenId| !ids.contains(id));
+    }
+
     /// Add this `ScreenId` as a parent.
     pub fn add_parent(
         &mut self,
@@ -38,6 +65,8 @@ impl From<Box<dyn Screen>> for ScreenNode {
             screen,
             parents: Vec::new(),
             children: Vec::new(),
+            entered: false,
+            pinned: false,
         }
     }
 }
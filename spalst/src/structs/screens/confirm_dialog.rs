@@ -0,0 +1,86 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Confirmation, Request, ScreenManagerRequest},
+    structs::AppState,
+    traits::Screen,
+};
+use core::{any::Any, fmt::Debug};
+use egui::{InnerResponse, InputState, Key, Modifiers, Response, Ui};
+
+/// Asks the user to confirm something, meant to be opened in a modal overlay (see
+/// `ScreenManagerRequest::OpenOverlay`).
+///
+/// Once the user confirms (also with Enter) or cancels (also with Escape), the overlay is closed
+/// with a `Confirmation<T>` that holds the `value` the dialog was made with, so the screen that
+/// opened it can tell what was confirmed (see `Screen::on_overlay_result()`).
+#[derive(Debug)]
+pub struct ConfirmDialog<T> {
+    /// The heading of the dialog.
+    title: String,
+    /// What the user is asked to confirm.
+    message: String,
+    /// The label of the button that confirms.
+    confirm_label: String,
+    /// The value handed back, until it is.
+    value: Option<T>,
+}
+
+impl<T> ConfirmDialog<T> {
+    /// Construct a new `ConfirmDialog` that hands `value` back once it is closed.
+    pub const fn new(
+        title: String,
+        message: String,
+        confirm_label: String,
+        value: T,
+    ) -> Self {
+        Self {
+            title,
+            message,
+            confirm_label,
+            value: Some(value),
+        }
+    }
+}
+
+impl<T: Debug + Send + Sync + 'static> Screen for ConfirmDialog<T> {
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        _state: &AppState,
+    ) -> Vec<Request> {
+        let mut confirmed: bool = false;
+        let mut cancelled: bool = false;
+        let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
+            let _: Response = ui.heading(&self.title);
+            let _: Response = ui.label(&self.message);
+            let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+                confirmed = ui.button(&self.confirm_label).clicked();
+                cancelled = ui.button("Cancel").clicked();
+            });
+        });
+        confirmed |= ui.input_mut(|input: &mut InputState| input.consume_key(Modifiers::NONE, Key::Enter));
+        cancelled |= ui.input_mut(|input: &mut InputState| input.consume_key(Modifiers::NONE, Key::Escape));
+
+        let Some(value) = (confirmed || cancelled)
+            .then(|| self.value.take())
+            .flatten()
+        else {
+            return Vec::new();
+        };
+        let confirmation: Confirmation<T> = if confirmed {
+            Confirmation::Confirmed(value)
+        } else {
+            Confirmation::Cancelled(value)
+        };
+        let result: Box<dyn Any + Send + Sync> = Box::new(confirmation);
+        vec![Request::ScreenManager(ScreenManagerRequest::CloseOverlay(
+            Some(result),
+        ))]
+    }
+}
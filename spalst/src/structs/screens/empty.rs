@@ -1,7 +1,7 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::{enums::Request, structs::App, traits::Screen};
+use crate::{enums::Request, structs::AppState, traits::Screen};
 use egui::Ui;
 
 /// A completely empty screen.
@@ -9,10 +9,14 @@ use egui::Ui;
 pub struct EmptyScreen;
 
 impl Screen for EmptyScreen {
+    fn title(&self) -> String {
+        "Spalst".to_owned()
+    }
+
     fn ui(
-        &self,
+        &mut self,
         _ui: &mut Ui,
-        _app: &App,
+        _state: &AppState,
     ) -> Vec<Request> {
         Vec::new()
     }
@@ -0,0 +1,93 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Request, SaveRequest, ScreenManagerRequest},
+    structs::{AppState, DisplayDuration, PauseScreen},
+    traits::Screen,
+};
+use core::time::Duration;
+use egui::{InnerResponse, InputState, Key, Response, Ui};
+
+/// The screen the active save is played on.
+#[derive(Debug)]
+pub struct GameScreen;
+
+impl GameScreen {
+    /// The name of the `Route` that leads to this screen.
+    pub const ROUTE: &'static str = "save";
+
+    /// Get the playtime of the active save including its ancestors and the playtime that wasn't
+    /// committed yet, if a save is active.
+    pub fn total_playtime(state: &AppState) -> Option<Duration> {
+        let playtime: Duration = state
+            .open_playthrough
+            .as_ref()?
+            .playtime_with_ancestors(state.active_save?)
+            .ok()?;
+        Some(playtime.saturating_add(state.playtime_clock.elapsed()))
+    }
+}
+
+impl Screen for GameScreen {
+    fn title(&self) -> String {
+        "Game".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        state: &AppState,
+    ) -> Vec<Request> {
+        let mut requests: Vec<Request> = Vec::new();
+        let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
+            if let (Some(playthrough), Some(id)) = (state.open_playthrough.as_ref(), state.active_save) {
+                let _: Response = ui.heading(format!("{} - Save {}", playthrough.name(), *id));
+            }
+            if let Some(playtime) = Self::total_playtime(state) {
+                let _: Response = ui.label(format!("Playtime: {}", DisplayDuration(playtime)));
+            }
+            if state.active_save.is_none()
+                && let Some(ref open_error) = state.open_error
+            {
+                let _: Response = ui.colored_label(ui.visuals().error_fg_color, open_error);
+            }
+            let _: Response = ui.label("Gameplay not implemented yet.");
+            if ui.button("Pause").clicked() || ui.input(|input: &InputState| input.key_pressed(Key::Escape)) {
+                requests.push(Request::ScreenManager(ScreenManagerRequest::OpenOverlay {
+                    screen: Box::new(PauseScreen),
+                    modal: true,
+                }));
+            }
+            if ui.button("Exit").clicked() {
+                // Nothing was started if continuing failed.
+                if state.active_save.is_some() {
+                    requests.push(Request::Save(SaveRequest::Stop));
+                }
+                requests.push(Request::ScreenManager(ScreenManagerRequest::Back));
+            }
+        });
+        requests
+    }
+
+    /// Autosave when another screen is shown instead of this one, or a modal overlay over it, like
+    /// the `PauseScreen`.
+    fn on_pause(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) {
+        requests.push(Request::Autosave);
+    }
+
+    /// Autosave when the game is left.
+    fn on_exit(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) {
+        requests.push(Request::Autosave);
+    }
+
+    fn is_gameplay(&self) -> bool {
+        true
+    }
+}
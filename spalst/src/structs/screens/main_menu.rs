@@ -2,8 +2,11 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 use crate::{
-    enums::{Request, ScreenManagerRequest},
-    structs::{App, screens::SettingsScreen},
+    enums::{PlaythroughRequest, Request, ScreenManagerRequest},
+    structs::{
+        AppState,
+        screens::{GameScreen, ManagePlaythroughsScreen, NewPlaythroughScreen, SettingsScreen},
+    },
     traits::Screen,
 };
 use egui::{InnerResponse, Response, Ui};
@@ -13,26 +16,62 @@ use elegance::Button;
 #[derive(Debug)]
 pub struct MainMenuScreen;
 
+impl MainMenuScreen {
+    /// The name of the `Route` that leads to this screen.
+    pub const ROUTE: &'static str = "main-menu";
+}
+
 impl Screen for MainMenuScreen {
+    fn title(&self) -> String {
+        "Main Menu".to_owned()
+    }
+
     fn ui(
-        &self,
+        &mut self,
         ui: &mut Ui,
-        app: &App,
+        state: &AppState,
     ) -> Vec<Request> {
         let mut requests: Vec<Request> = Vec::new();
         let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
             let _: Response = ui.heading("Spalst");
             {
-                let response: Response = ui.add(Button::new("Continue").enabled(false));
-                let _: Response = response.on_disabled_hover_text("Not implemented yet.");
+                let response: Response = ui
+                    .add(Button::new("Continue").enabled(state.continue_target.is_some()))
+                    .on_disabled_hover_text("There is no save to continue from.");
+                if let Some(ref name) = state.continue_target
+                    && response
+                        .on_hover_text(format!("Continue playing \"{name}\"."))
+                        .clicked()
+                {
+                    requests.push(Request::Playthrough(PlaythroughRequest::Continue(
+                        name.clone(),
+                    )));
+                    requests.push(Request::ScreenManager(
+                        ScreenManagerRequest::AddAndSelectScreen {
+                            screen: Box::new(GameScreen),
+                            parent_id: state.screen_manager.current_id(),
+                        },
+                    ));
+                }
             }
-            {
-                let response: Response = ui.add(Button::new("New Playthrough").enabled(false));
-                let _: Response = response.on_disabled_hover_text("Not implemented yet.");
+            if ui.button("New Playthrough").clicked() {
+                requests.push(Request::ScreenManager(
+                    ScreenManagerRequest::AddOrFocusScreen {
+                        screen: Box::new(NewPlaythroughScreen::new()),
+                        parent_id: state.screen_manager.current_id(),
+                    },
+                ));
+                // The names of the stored playthroughs are needed to reject taken ones.
+                requests.push(Request::Playthrough(PlaythroughRequest::Refresh));
             }
-            {
-                let response: Response = ui.add(Button::new("Manage Playthroughs").enabled(false));
-                let _: Response = response.on_disabled_hover_text("Not implemented yet.");
+            if ui.button("Manage Playthroughs").clicked() {
+                requests.push(Request::ScreenManager(
+                    ScreenManagerRequest::AddOrFocusScreen {
+                        screen: Box::new(ManagePlaythroughsScreen::new()),
+                        parent_id: state.screen_manager.current_id(),
+                    },
+                ));
+                requests.push(Request::Playthrough(PlaythroughRequest::Refresh));
             }
             {
                 let response: Response = ui.add(Button::new("Achievements").enabled(false));
@@ -40,9 +79,9 @@ impl Screen for MainMenuScreen {
             }
             if ui.button("Settings").clicked() {
                 requests.push(Request::ScreenManager(
-                    ScreenManagerRequest::AddAndSelectScreen {
-                        screen: Box::new(SettingsScreen),
-                        parent_id: app.state.lock().unwrap().screen_manager.current_id(),
+                    ScreenManagerRequest::AddOrFocusScreen {
+                        screen: Box::new(SettingsScreen::new()),
+                        parent_id: state.screen_manager.current_id(),
                     },
                 ));
             }
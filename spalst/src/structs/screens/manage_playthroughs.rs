@@ -0,0 +1,302 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Confirmation, PlaythroughAction, PlaythroughRequest, Request, ScreenManagerRequest},
+    statics::HOME_DIR,
+    structs::{AppState, ConfirmDialog, DisplayDuration, DisplayTime, PlaythroughStore, PlaythroughSummary, SaveTreeScreen},
+    traits::Screen,
+};
+use chrono::{DateTime, Utc};
+use core::any::Any;
+use derive_new::new;
+use egui::{Grid, InnerResponse, Response, Ui};
+use elegance::Button;
+use std::path::PathBuf;
+
+/// Lists every stored `Playthrough`, and lets the user rename, duplicate, export or delete them, or
+/// import new ones.
+///
+/// Every action has to be confirmed before its `PlaythroughRequest` is sent. Deleting is confirmed
+/// in a `ConfirmDialog`, and the rest below the table.
+#[derive(Debug, new)]
+pub struct ManagePlaythroughsScreen {
+    /// The action waiting to be confirmed, if any.
+    #[new(default)]
+    action: Option<PlaythroughAction>,
+}
+
+impl ManagePlaythroughsScreen {
+    /// The name of the `Route` that leads to this screen.
+    pub const ROUTE: &'static str = "playthroughs";
+
+    /// Format a time for display, in local time.
+    fn format_time(
+        time: Option<DateTime<Utc>>,
+        fallback: &str,
+    ) -> String {
+        time.map_or_else(
+            || fallback.to_owned(),
+            |time: DateTime<Utc>| DisplayTime(time).to_string(),
+        )
+    }
+
+    /// Describe what makes this name unusable for a new or renamed `Playthrough`, if anything.
+    pub(super) fn new_name_problem(
+        name: &str,
+        state: &AppState,
+    ) -> Option<String> {
+        PlaythroughStore::name_problem(name).or_else(|| {
+            state
+                .playthrough_summaries
+                .iter()
+                .any(|summary: &PlaythroughSummary| summary.name == name)
+                .then(|| format!("A playthrough named \"{name}\" already exists."))
+        })
+    }
+
+    /// Render the table of `Playthrough`s. Returns the action the user started, if any.
+    ///
+    /// Opening the saves of a `Playthrough` doesn't need to be confirmed, and deleting one is
+    /// confirmed in a `ConfirmDialog`, so their requests are pushed to `requests` right away.
+    fn table_ui(
+        ui: &mut Ui,
+        state: &AppState,
+        requests: &mut Vec<Request>,
+    ) -> Option<PlaythroughAction> {
+        let mut action: Option<PlaythroughAction> = None;
+        let _: InnerResponse<()> = Grid::new("playthroughs")
+            .striped(true)
+            .show(ui, |ui: &mut Ui| {
+                let _: Response = ui.strong("Name");
+                let _: Response = ui.strong("Playtime");
+                let _: Response = ui.strong("Created");
+                let _: Response = ui.strong("Last played");
+                ui.end_row();
+
+                for summary in &state.playthrough_summaries {
+                    let _: Response = ui.label(&summary.name);
+                    let _: Response = ui.label(DisplayDuration(summary.playtime).to_string());
+                    let _: Response = ui.label(Self::format_time(summary.created, "-"));
+                    let _: Response = ui.label(Self::format_time(summary.last_played, "Never"));
+                    let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+                        if ui.button("Saves").clicked() {
+                            requests.push(Request::Playthrough(PlaythroughRequest::Open(
+                                summary.name.clone(),
+                            )));
+                            requests.push(Request::ScreenManager(
+                                ScreenManagerRequest::AddAndSelectScreen {
+                                    screen: Box::new(SaveTreeScreen::new()),
+                                    parent_id: state.screen_manager.current_id(),
+                                },
+                            ));
+                        }
+                        if ui.button("Rename").clicked() {
+                            action = Some(PlaythroughAction::Rename {
+                                name: summary.name.clone(),
+                                new_name: summary.name.clone(),
+                            });
+                        }
+                        if ui.button("Duplicate").clicked() {
+                            action = Some(PlaythroughAction::Duplicate {
+                                name: summary.name.clone(),
+                                new_name: format!("{} (copy)", summary.name),
+                            });
+                        }
+                        if ui.button("Export").clicked() {
+                            action = Some(PlaythroughAction::Export {
+                                name: summary.name.clone(),
+                                path: HOME_DIR
+                                    .join(format!(
+                                        "{}.{}",
+                                        summary.name,
+                                        PlaythroughStore::ARCHIVE_EXTENSION
+                                    ))
+                                    .display()
+                                    .to_string(),
+                            });
+                        }
+                        if ui.button("Delete").clicked() {
+                            let dialog: ConfirmDialog<PlaythroughRequest> = ConfirmDialog::new(
+                                "Delete Playthrough".to_owned(),
+                                format!(
+                                    "Delete \"{}\" and all of its backups? This can't be undone.",
+                                    summary.name
+                                ),
+                                "Delete".to_owned(),
+                                PlaythroughRequest::Delete(summary.name.clone()),
+                            );
+                            requests.push(Request::ScreenManager(ScreenManagerRequest::OpenOverlay {
+                                screen: Box::new(dialog),
+                                modal: true,
+                            }));
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        action
+    }
+
+    /// Render the confirmation of the pending action.
+    ///
+    /// Returns the `Request` to send if the action was confirmed, and whether the action is
+    /// still pending.
+    fn confirmation_ui(
+        ui: &mut Ui,
+        action: &mut PlaythroughAction,
+        state: &AppState,
+    ) -> (Option<PlaythroughRequest>, bool) {
+        let (request, problem): (PlaythroughRequest, Option<String>) = match *action {
+            PlaythroughAction::Rename {
+                ref name,
+                ref mut new_name,
+            } => {
+                let _: Response = ui.label(format!("Rename \"{name}\" to:"));
+                let _: Response = ui.text_edit_singleline(new_name);
+                (
+                    PlaythroughRequest::Rename {
+                        name: name.clone(),
+                        new_name: new_name.clone(),
+                    },
+                    Self::new_name_problem(new_name, state),
+                )
+            }
+            PlaythroughAction::Duplicate {
+                ref name,
+                ref mut new_name,
+            } => {
+                let _: Response = ui.label(format!("Duplicate \"{name}\" as:"));
+                let _: Response = ui.text_edit_singleline(new_name);
+                (
+                    PlaythroughRequest::Duplicate {
+                        name: name.clone(),
+                        new_name: new_name.clone(),
+                    },
+                    Self::new_name_problem(new_name, state),
+                )
+            }
+            PlaythroughAction::Export {
+                ref name,
+                ref mut path,
+            } => {
+                let _: Response = ui.label(format!("Export \"{name}\" to:"));
+                let _: Response = ui.text_edit_singleline(path);
+                (
+                    PlaythroughRequest::Export {
+                        name: name.clone(),
+                        save: None,
+                        path: PathBuf::from(path.as_str()),
+                    },
+                    path.is_empty()
+                        .then(|| "Enter where to export it to.".to_owned()),
+                )
+            }
+            PlaythroughAction::Import {
+                ref mut path,
+                ref mut name,
+            } => {
+                let _: Response = ui.label("Import the archive at:");
+                let _: Response = ui.text_edit_singleline(path);
+                let _: Response = ui.label("As (leave empty to keep the name it was exported with):");
+                let _: Response = ui.text_edit_singleline(name);
+                let problem: Option<String> = if path.is_empty() {
+                    Some("Enter the path of the archive.".to_owned())
+                } else if name.is_empty() {
+                    None
+                } else {
+                    Self::new_name_problem(name, state)
+                };
+                (
+                    PlaythroughRequest::Import {
+                        path: PathBuf::from(path.as_str()),
+                        name: (!name.is_empty()).then(|| name.clone()),
+                    },
+                    problem,
+                )
+            }
+        };
+        if let Some(ref problem) = problem {
+            let _: Response = ui.colored_label(ui.visuals().error_fg_color, problem);
+        }
+
+        let mut confirmed: bool = false;
+        let mut cancelled: bool = false;
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            confirmed = ui
+                .add(Button::new("Confirm").enabled(problem.is_none()))
+                .clicked();
+            cancelled = ui.button("Cancel").clicked();
+        });
+
+        (confirmed.then_some(request), !confirmed && !cancelled)
+    }
+}
+
+impl Screen for ManagePlaythroughsScreen {
+    fn title(&self) -> String {
+        "Manage Playthroughs".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        state: &AppState,
+    ) -> Vec<Request> {
+        let mut requests: Vec<Request> = Vec::new();
+        let action: &mut Option<PlaythroughAction> = &mut self.action;
+
+        let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
+            let _: Response = ui.heading("Manage Playthroughs");
+
+            if state.playthrough_summaries.is_empty() {
+                let _: Response = ui.label("There are no playthroughs yet.");
+            } else if let Some(new_action) = Self::table_ui(ui, state, &mut requests) {
+                *action = Some(new_action);
+            } else {
+                // No new action was started, so the pending one (if any) stays.
+            }
+
+            if let Some(mut pending) = action.take() {
+                let _: Response = ui.separator();
+                let (request, still_pending): (Option<PlaythroughRequest>, bool) = Self::confirmation_ui(ui, &mut pending, state);
+                requests.extend(request.map(Request::Playthrough));
+                *action = still_pending.then_some(pending);
+            }
+
+            if let Some(ref manage_error) = state.manage_error {
+                let _: Response = ui.separator();
+                let _: Response = ui.colored_label(ui.visuals().error_fg_color, manage_error);
+            }
+            if let Some(ref archive_status) = state.archive_status {
+                let _: Response = ui.separator();
+                let _: Response = ui.label(archive_status);
+            }
+
+            let _: Response = ui.separator();
+            if ui.button("Import").clicked() {
+                *action = Some(PlaythroughAction::Import {
+                    path: String::new(),
+                    name: String::new(),
+                });
+            }
+            if ui.button("Back").clicked() {
+                requests.push(Request::ScreenManager(ScreenManagerRequest::Back));
+            }
+        });
+        requests
+    }
+
+    /// Send the `PlaythroughRequest` of a confirmed `ConfirmDialog`.
+    fn on_overlay_result(
+        &mut self,
+        result: Box<dyn Any + Send + Sync>,
+        requests: &mut Vec<Request>,
+    ) {
+        if let Ok(confirmation) = result.downcast::<Confirmation<PlaythroughRequest>>()
+            && let Confirmation::Confirmed(request) = *confirmation
+        {
+            requests.push(Request::Playthrough(request));
+        }
+    }
+}
@@ -1,10 +1,24 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
+mod confirm_dialog;
 mod empty;
+mod game;
 mod main_menu;
+mod manage_playthroughs;
+mod new_playthrough;
+mod pause;
+mod save_diff;
+mod save_tree;
 mod settings;
 
+pub use confirm_dialog::ConfirmDialog;
 pub use empty::EmptyScreen;
+pub use game::GameScreen;
 pub use main_menu::MainMenuScreen;
+pub use manage_playthroughs::ManagePlaythroughsScreen;
+pub use new_playthrough::NewPlaythroughScreen;
+pub use pause::PauseScreen;
+pub use save_diff::SaveDiffScreen;
+pub use save_tree::SaveTreeScreen;
 pub use settings::SettingsScreen;
@@ -0,0 +1,219 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Difficulty, PlaythroughRequest, Request, ScreenManagerRequest, WizardStep},
+    structs::{AppState, GameScreen, ManagePlaythroughsScreen, PlaythroughDraft, PlaythroughSettings, ScreenId},
+    traits::Screen,
+};
+use derive_new::new;
+use egui::{InnerResponse, Response, Ui};
+use elegance::Button;
+
+/// Walks the user through creating a new `Playthrough`, one `WizardStep` at a time, and starts
+/// playing it once it is created.
+///
+/// Every step is validated as it is filled in, and the next one can't be reached while anything is
+/// wrong.
+///
+/// While a draft is started, the screen is pinned (see `ScreenManager::set_pinned()`), so that
+/// leaving the wizard keeps the draft for when it is opened again.
+#[derive(Debug, new)]
+pub struct NewPlaythroughScreen {
+    /// What the user entered so far.
+    #[new(default)]
+    draft: PlaythroughDraft,
+    /// Whether the screen was pinned to keep the draft.
+    #[new(default)]
+    pinned: bool,
+}
+
+impl NewPlaythroughScreen {
+    /// The name of the `Route` that leads to this screen.
+    pub const ROUTE: &'static str = "new-playthrough";
+
+    /// Describe what keeps the user from leaving the current step, if anything.
+    fn step_problem(
+        draft: &PlaythroughDraft,
+        state: &AppState,
+    ) -> Option<String> {
+        match draft.step {
+            // Another `Playthrough` may have taken the name since it was entered.
+            WizardStep::Name | WizardStep::Confirm => ManagePlaythroughsScreen::new_name_problem(&draft.name, state),
+            WizardStep::Seed => draft.seed_problem(),
+            WizardStep::Rules => None,
+        }
+    }
+
+    /// Render the list of steps, with the current one highlighted.
+    fn steps_ui(
+        ui: &mut Ui,
+        current: WizardStep,
+    ) {
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            for (number, step) in (1_u8..).zip(WizardStep::ALL) {
+                let text: String = format!("{number}. {}", step.title());
+                let _: Response = if step == current {
+                    ui.strong(text)
+                } else {
+                    ui.weak(text)
+                };
+            }
+        });
+    }
+
+    /// Render the current step.
+    fn step_ui(
+        ui: &mut Ui,
+        draft: &mut PlaythroughDraft,
+    ) {
+        match draft.step {
+            WizardStep::Name => {
+                let _: Response = ui.label("What should the playthrough be called?");
+                let _: Response = ui.text_edit_singleline(&mut draft.name);
+            }
+            WizardStep::Rules => {
+                let _: Response = ui.label("How hard should the game be?");
+                for difficulty in Difficulty::ALL {
+                    let _: Response = ui
+                        .radio_value(&mut draft.difficulty, difficulty, difficulty.name())
+                        .on_hover_text(difficulty.description());
+                }
+                let _: Response = ui
+                    .checkbox(&mut draft.permadeath, "Permadeath")
+                    .on_hover_text("Dying ends the branch being played, instead of going back to the last save.");
+            }
+            WizardStep::Seed => {
+                let _: Response = ui.label("Enter a world seed, or leave it empty for a random world:");
+                let _: Response = ui.text_edit_singleline(&mut draft.seed);
+            }
+            WizardStep::Confirm => {
+                let _: Response = ui.label(format!("Name: {}", draft.name));
+                let _: Response = ui.label(format!("Difficulty: {}", draft.difficulty.name()));
+                let _: Response = ui.label(format!(
+                    "Permadeath: {}",
+                    if draft.permadeath {
+                        "On"
+                    } else {
+                        "Off"
+                    }
+                ));
+                let _: Response = ui.label(format!(
+                    "World seed: {}",
+                    if draft.seed.is_empty() {
+                        "Random"
+                    } else {
+                        draft.seed.trim()
+                    }
+                ));
+            }
+        }
+    }
+}
+
+impl Screen for NewPlaythroughScreen {
+    fn title(&self) -> String {
+        "New Playthrough".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        state: &AppState,
+    ) -> Vec<Request> {
+        let mut requests: Vec<Request> = Vec::new();
+        let draft: &mut PlaythroughDraft = &mut self.draft;
+
+        let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
+            let _: Response = ui.heading("New Playthrough");
+            Self::steps_ui(ui, draft.step);
+            let _: Response = ui.separator();
+
+            Self::step_ui(ui, draft);
+            let problem: Option<String> = Self::step_problem(draft, state);
+            if let Some(ref problem) = problem {
+                let _: Response = ui.colored_label(ui.visuals().error_fg_color, problem);
+            }
+            if draft.step == WizardStep::Confirm
+                && let Some(ref create_error) = state.create_error
+            {
+                let _: Response = ui.colored_label(ui.visuals().error_fg_color, create_error);
+            }
+
+            let _: Response = ui.separator();
+            let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+                if ui.button("Back").clicked() {
+                    match draft.step.previous() {
+                        Some(previous) => draft.step = previous,
+                        None => requests.push(Request::ScreenManager(ScreenManagerRequest::Back)),
+                    }
+                }
+                if ui
+                    .add(Button::new("Discard").enabled(draft.is_started()))
+                    .on_hover_text("Forget everything entered so far and leave.")
+                    .clicked()
+                {
+                    *draft = PlaythroughDraft::default();
+                    requests.push(Request::ScreenManager(ScreenManagerRequest::Back));
+                }
+                if let Some(next) = draft.step.next() {
+                    if ui
+                        .add(Button::new("Next").enabled(problem.is_none()))
+                        .clicked()
+                    {
+                        draft.step = next;
+                    }
+                } else if ui
+                    .add(Button::new("Create").enabled(problem.is_none()))
+                    .clicked()
+                {
+                    // The game replaces the wizard once the `Playthrough` exists, so leaving it goes
+                    // back to where the wizard was opened from. The wizard is unpinned first, so
+                    // that it is dropped along with the draft. If creating fails, the wizard stays
+                    // and shows why.
+                    let then: Vec<Request> = vec![
+                        Request::ScreenManager(ScreenManagerRequest::Unpin(
+                            state.screen_manager.current_id(),
+                        )),
+                        Request::ScreenManager(ScreenManagerRequest::Back),
+                        Request::ScreenManager(ScreenManagerRequest::AddAndSelectScreen {
+                            screen: Box::new(GameScreen),
+                            parent_id: state
+                                .screen_manager
+                                .previous_id()
+                                .unwrap_or_else(|| state.screen_manager.root_id()),
+                        }),
+                    ];
+                    requests.push(Request::Playthrough(PlaythroughRequest::Create {
+                        name: draft.name.trim().to_owned(),
+                        settings: PlaythroughSettings {
+                            difficulty: draft.difficulty,
+                            permadeath: draft.permadeath,
+                            seed: draft.seed(),
+                        },
+                        then,
+                    }));
+                } else {
+                    // Creating wasn't clicked, so the wizard stays on its last step.
+                }
+            });
+        });
+
+        let started: bool = self.draft.is_started();
+        if started != self.pinned {
+            self.pinned = started;
+            let id: ScreenId = state.screen_manager.current_id();
+            // Goes before the other requests, so that leaving the wizard already knows whether to
+            // keep it.
+            requests.insert(
+                0,
+                Request::ScreenManager(if started {
+                    ScreenManagerRequest::Pin(id)
+                } else {
+                    ScreenManagerRequest::Unpin(id)
+                }),
+            );
+        }
+        requests
+    }
+}
@@ -0,0 +1,48 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Request, SaveRequest, ScreenManagerRequest},
+    structs::{AppState, DisplayDuration, GameScreen},
+    traits::Screen,
+};
+use egui::{InnerResponse, InputState, Key, Modifiers, Response, Ui};
+
+/// Shown in a modal overlay over a `GameScreen` while the game is paused. The `PlaytimeClock`
+/// doesn't run while it is shown.
+#[derive(Debug)]
+pub struct PauseScreen;
+
+impl Screen for PauseScreen {
+    fn title(&self) -> String {
+        "Paused".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        state: &AppState,
+    ) -> Vec<Request> {
+        let mut requests: Vec<Request> = Vec::new();
+        let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
+            let _: Response = ui.heading("Paused");
+            if let Some(playtime) = GameScreen::total_playtime(state) {
+                let _: Response = ui.label(format!("Playtime: {}", DisplayDuration(playtime)));
+            }
+            if ui.button("Resume").clicked() || ui.input_mut(|input: &mut InputState| input.consume_key(Modifiers::NONE, Key::Escape)) {
+                requests.push(Request::ScreenManager(ScreenManagerRequest::CloseOverlay(
+                    None,
+                )));
+            }
+            if ui.button("Save and Exit").clicked() {
+                requests.push(Request::Save(SaveRequest::Stop));
+                // Close this, and then go back from the `GameScreen` to where it was opened from.
+                requests.push(Request::ScreenManager(ScreenManagerRequest::CloseOverlay(
+                    None,
+                )));
+                requests.push(Request::ScreenManager(ScreenManagerRequest::Back));
+            }
+        });
+        requests
+    }
+}
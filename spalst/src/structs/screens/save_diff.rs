@@ -0,0 +1,172 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{Request, SaveChange, SaveEntry, ScreenManagerRequest},
+    structs::{AppState, Playthrough, SaveDiff, SaveId, SaveTreeScreen},
+    traits::Screen,
+};
+use color_eyre::{Report, Result};
+use derive_new::new;
+use egui::{Color32, Grid, InnerResponse, Response, RichText, ScrollArea, Ui, scroll_area::ScrollAreaOutput};
+use serde_json::Value;
+
+/// Shows what changed between two saves of the open `Playthrough` side by side (see
+/// `Playthrough::diff()`).
+///
+/// The saves can only change while another screen is the current one, so the diff is computed
+/// once this screen is shown, and kept until it stops being the current one.
+#[derive(Debug, new)]
+pub struct SaveDiffScreen {
+    /// The older save, shown on the left.
+    from: SaveId,
+    /// The newer save, shown on the right.
+    to: SaveId,
+    /// The diff of the two saves, or why they can't be compared, once it is computed.
+    #[new(default)]
+    diff: Option<Result<SaveDiff>>,
+}
+
+impl SaveDiffScreen {
+    /// Get the title of the save this `SaveId` points to, as shown in the save tree.
+    fn save_title(
+        playthrough: &Playthrough,
+        id: SaveId,
+    ) -> String {
+        playthrough.get_save(id).map_or_else(
+            |_: Report| format!("Save {}", *id),
+            |entry: &SaveEntry| SaveTreeScreen::save_title(id, entry),
+        )
+    }
+
+    /// Describe how the two saves are related in the tree.
+    fn relation(
+        playthrough: &Playthrough,
+        diff: &SaveDiff,
+    ) -> String {
+        match diff.common_ancestor {
+            None => "The saves are in different trees.".to_owned(),
+            Some(ancestor) if ancestor == diff.from => format!(
+                "{} is an ancestor of {}.",
+                Self::save_title(playthrough, diff.from),
+                Self::save_title(playthrough, diff.to)
+            ),
+            Some(ancestor) if ancestor == diff.to => format!(
+                "{} is an ancestor of {}.",
+                Self::save_title(playthrough, diff.to),
+                Self::save_title(playthrough, diff.from)
+            ),
+            Some(ancestor) => format!(
+                "Both saves were forked from {}.",
+                Self::save_title(playthrough, ancestor)
+            ),
+        }
+    }
+
+    /// Render one side of a change. Values that only one side has are shown as missing on the
+    /// other.
+    fn value_ui(
+        ui: &mut Ui,
+        value: Option<&Value>,
+    ) {
+        let _: Response = match value {
+            Some(value) => ui.monospace(value.to_string()),
+            None => ui.weak("(none)"),
+        };
+    }
+
+    /// Render the changes as a table, with the older save on the left and the newer one on the
+    /// right.
+    fn changes_ui(
+        &self,
+        ui: &mut Ui,
+        playthrough: &Playthrough,
+        diff: &SaveDiff,
+    ) {
+        let _: InnerResponse<()> = Grid::new("save_diff")
+            .striped(true)
+            .show(ui, |ui: &mut Ui| {
+                let _: Response = ui.strong("Field");
+                let _: Response = ui.strong(Self::save_title(playthrough, self.from));
+                let _: Response = ui.strong(Self::save_title(playthrough, self.to));
+                ui.end_row();
+
+                for change in &diff.changes {
+                    let color: Color32 = match *change {
+                        SaveChange::Added { .. } | SaveChange::Removed { .. } => ui.visuals().warn_fg_color,
+                        SaveChange::Changed { .. } => ui.visuals().text_color(),
+                    };
+                    let _: Response = ui.label(RichText::new(change.path()).monospace().color(color));
+                    Self::value_ui(ui, change.before());
+                    Self::value_ui(ui, change.after());
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+impl Screen for SaveDiffScreen {
+    fn title(&self) -> String {
+        "Compare Saves".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        state: &AppState,
+    ) -> Vec<Request> {
+        let mut requests: Vec<Request> = Vec::new();
+        if ui.button("Back").clicked() {
+            requests.push(Request::ScreenManager(ScreenManagerRequest::Back));
+        }
+
+        let Some(ref playthrough) = state.open_playthrough else {
+            let _: Response = ui.label("Loading...");
+            return requests;
+        };
+        let _: Response = ui.heading(format!(
+            "{} compared to {}",
+            Self::save_title(playthrough, self.to),
+            Self::save_title(playthrough, self.from)
+        ));
+
+        if self.diff.is_none() {
+            self.diff = Some(playthrough.diff(self.from, self.to));
+        }
+        match self.diff {
+            None => unreachable!("The diff must have been computed right above."),
+            Some(Ok(ref diff)) => {
+                let _: Response = ui.label(Self::relation(playthrough, diff));
+                let _: Response = ui.separator();
+                if diff.is_empty() {
+                    let _: Response = ui.label("The saves are the same.");
+                } else {
+                    let _: ScrollAreaOutput<()> = ScrollArea::both().show(ui, |ui: &mut Ui| self.changes_ui(ui, playthrough, diff));
+                }
+            }
+            Some(Err(ref error)) => {
+                let _: Response = ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    format!("The saves can't be compared: {error}"),
+                );
+            }
+        }
+        requests
+    }
+
+    /// Forget the diff, since the saves may change while another screen is shown.
+    fn on_pause(
+        &mut self,
+        _requests: &mut Vec<Request>,
+    ) {
+        self.diff = None;
+    }
+
+    /// Forget the diff, since the saves may change while another screen is shown.
+    fn on_exit(
+        &mut self,
+        _requests: &mut Vec<Request>,
+    ) {
+        self.diff = None;
+    }
+}
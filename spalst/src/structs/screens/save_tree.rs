@@ -0,0 +1,514 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{ColorTag, PlaythroughRequest, Request, SaveEntry, SaveRequest, ScreenManagerRequest},
+    statics::HOME_DIR,
+    structs::{AppState, DisplayDuration, DisplayTime, GameScreen, Playthrough, PlaythroughSettings, PlaythroughStore, SaveDiffScreen, SaveFilter, SaveId, SaveMetadata, SaveTreeLayout},
+    traits::Screen,
+};
+use core::time::Duration;
+use derive_new::new;
+use egui::{Align2, Color32, FontId, InnerResponse, Pos2, Rect, Response, RichText, Scene, Sense, Stroke, StrokeKind, Ui, layers::ShapeIdx, vec2};
+use elegance::Button;
+
+/// Draws the saves of the open `Playthrough` as a tree that can be zoomed and panned.
+///
+/// Clicking a save selects it, after which it can be forked, deleted, restored, played, described
+/// (see `SaveMetadata`) or compared with another save (see `SaveDiffScreen`). Saves can be
+/// searched by what was written about them, and the ones that don't match are dimmed.
+#[derive(Debug, new)]
+pub struct SaveTreeScreen {
+    /// The save that was clicked last, if any.
+    #[new(default)]
+    selected: Option<SaveId>,
+    /// The part of the tree that is visible. `Scene` fits the whole tree into view when given an
+    /// empty rectangle.
+    #[new(value = "Rect::ZERO")]
+    scene_rect: Rect,
+    /// What the saves are searched for.
+    #[new(default)]
+    filter: SaveFilter,
+    /// The save whose `SaveMetadata` is being edited, along with the edited copy of it.
+    #[new(default)]
+    editing: Option<(SaveId, SaveMetadata)>,
+    /// The save to compare the selected one with, if one was picked.
+    #[new(default)]
+    compared: Option<SaveId>,
+}
+
+impl SaveTreeScreen {
+    /// The name of the `Route` that leads to this screen.
+    pub const ROUTE: &'static str = "playthrough";
+
+    /// Get the title of a save: its name (or its index, if it wasn't named), followed by anything
+    /// noteworthy about it.
+    pub(super) fn save_title(
+        id: SaveId,
+        entry: &SaveEntry,
+    ) -> String {
+        let metadata: &SaveMetadata = entry.metadata();
+        let name: String = if !metadata.name.is_empty() {
+            metadata.name.clone()
+        } else if entry.is_autosave() {
+            format!("Autosave {}", *id)
+        } else {
+            format!("Save {}", *id)
+        };
+        let flags: Vec<&str> = [
+            (metadata.pinned, "pinned"),
+            (
+                matches!(*entry, SaveEntry::Active(ref save) if save.is_sealed()),
+                "sealed",
+            ),
+            (matches!(*entry, SaveEntry::Trashed(_)), "in trash"),
+            (matches!(*entry, SaveEntry::Deleted(_)), "deleted"),
+        ]
+        .into_iter()
+        .filter_map(|(applies, flag): (bool, &str)| applies.then_some(flag))
+        .collect();
+
+        if flags.is_empty() {
+            name
+        } else {
+            format!("{name} ({})", flags.join(", "))
+        }
+    }
+
+    /// Render a toggle for every `ColorTag`, in its colour. Returns the one that was clicked, if
+    /// any.
+    fn tag_toggles_ui(
+        ui: &mut Ui,
+        is_selected: impl Fn(ColorTag) -> bool,
+    ) -> Option<ColorTag> {
+        let mut clicked: Option<ColorTag> = None;
+        for tag in ColorTag::ALL {
+            if ui
+                .selectable_label(
+                    is_selected(tag),
+                    RichText::new(tag.name()).color(tag.color()),
+                )
+                .clicked()
+            {
+                clicked = Some(tag);
+            }
+        }
+        clicked
+    }
+
+    /// Render the search bar, along with the saves that match it. Clicking one of them selects it.
+    fn search_ui(
+        ui: &mut Ui,
+        playthrough: &Playthrough,
+        filter: &mut SaveFilter,
+        selected: &mut Option<SaveId>,
+    ) {
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            let _: Response = ui.label("Search:");
+            let _: Response = ui.text_edit_singleline(&mut filter.text);
+            if let Some(tag) = Self::tag_toggles_ui(ui, |tag: ColorTag| filter.tags.contains(&tag)) {
+                if filter.tags.contains(&tag) {
+                    filter.tags.retain(|other: &ColorTag| *other != tag);
+                } else {
+                    filter.tags.push(tag);
+                }
+            }
+            let _: Response = ui.checkbox(&mut filter.pinned_only, "Pinned only");
+            if !filter.is_empty() && ui.button("Clear").clicked() {
+                *filter = SaveFilter::default();
+            }
+        });
+        if filter.is_empty() {
+            return;
+        }
+
+        let matching: Vec<SaveId> = playthrough.search(filter);
+        let _: InnerResponse<()> = ui.horizontal_wrapped(|ui: &mut Ui| {
+            let _: Response = ui.label(format!("{} matching saves:", matching.len()));
+            for id in matching {
+                let Ok(entry) = playthrough.get_save(id) else {
+                    continue;
+                };
+                if ui
+                    .selectable_label(*selected == Some(id), Self::save_title(id, entry))
+                    .clicked()
+                {
+                    *selected = Some(id);
+                }
+            }
+        });
+    }
+
+    /// Render the details of the selected save, along with what can be done with it.
+    ///
+    /// Clicking "Edit details" starts editing a copy of its `SaveMetadata`, which is put into
+    /// `editing`.
+    fn selection_ui(
+        ui: &mut Ui,
+        state: &AppState,
+        id: SaveId,
+        entry: &SaveEntry,
+        playtime_with_ancestors: Duration,
+        editing: &mut Option<(SaveId, SaveMetadata)>,
+        requests: &mut Vec<Request>,
+    ) {
+        let (active, sealed, status): (bool, bool, String) = match *entry {
+            SaveEntry::Active(ref save) => (
+                true,
+                save.is_sealed(),
+                format!("Selected {}", Self::save_title(id, entry)),
+            ),
+            SaveEntry::Trashed(ref trashed_save) => (
+                false,
+                false,
+                format!(
+                    "Selected {} (deleted {}, can be restored until {})",
+                    Self::save_title(id, entry),
+                    DisplayTime(trashed_save.deleted_at()),
+                    DisplayTime(trashed_save.expires_at(state.playthrough_store.trash_retention()))
+                ),
+            ),
+            SaveEntry::Deleted(_) => (
+                false,
+                false,
+                format!("Selected {}", Self::save_title(id, entry)),
+            ),
+        };
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            let _: Response = ui.strong(status);
+            let _: Response = ui.label(format!(
+                "Playtime: {} ({} including its ancestors), created: {}",
+                DisplayDuration(entry.get_playtime()),
+                DisplayDuration(playtime_with_ancestors),
+                DisplayTime(entry.get_birth_time())
+            ));
+
+            if ui.button("Edit details").clicked() {
+                *editing = Some((id, entry.metadata().clone()));
+            }
+            if ui.add(Button::new("Fork").enabled(active)).clicked() {
+                requests.push(Request::Save(SaveRequest::Fork(id)));
+            }
+            if ui.add(Button::new("Delete").enabled(active)).clicked() {
+                requests.push(Request::Save(SaveRequest::Delete(id)));
+            }
+            if matches!(*entry, SaveEntry::Trashed(_)) && ui.button("Restore").clicked() {
+                requests.push(Request::Save(SaveRequest::Restore(id)));
+            }
+            if let Some(ref playthrough) = state.open_playthrough
+                && ui
+                    .button("Export branch")
+                    .on_hover_text("Export this save and its ancestors into an archive in your home directory.")
+                    .clicked()
+            {
+                requests.push(Request::Playthrough(PlaythroughRequest::Export {
+                    name: playthrough.name().to_owned(),
+                    save: Some((*id).into()),
+                    path: HOME_DIR
+                        .join(format!(
+                            "{} (save {}).{}",
+                            playthrough.name(),
+                            *id,
+                            PlaythroughStore::ARCHIVE_EXTENSION
+                        ))
+                        .to_path_buf(),
+                }));
+            }
+            let play: Response = ui
+                .add(Button::new("Play").enabled(active && !sealed))
+                .on_disabled_hover_text(if sealed {
+                    "This save was forked, so only its forks can be played."
+                } else {
+                    "Deleted saves can't be played."
+                });
+            let fork_and_play: Response = ui.add(Button::new("Fork and play").enabled(active));
+            // The game is only shown once the save is being played, so that if playing it fails,
+            // the tree stays and shows why.
+            let show_game = || -> Vec<Request> {
+                vec![Request::ScreenManager(
+                    ScreenManagerRequest::AddAndSelectScreen {
+                        screen: Box::new(GameScreen),
+                        parent_id: state.screen_manager.current_id(),
+                    },
+                )]
+            };
+            if play.clicked() {
+                requests.push(Request::Save(SaveRequest::Play {
+                    id,
+                    then: show_game(),
+                }));
+            } else if fork_and_play.clicked() {
+                requests.push(Request::Save(SaveRequest::ForkAndPlay {
+                    id,
+                    then: show_game(),
+                }));
+            } else {
+                // Neither was clicked, so nothing is played.
+            }
+        });
+        if !entry.metadata().notes.is_empty() {
+            let _: Response = ui.label(&entry.metadata().notes);
+        }
+    }
+
+    /// Render what is needed to compare the selected save with another one.
+    ///
+    /// The first save to compare is kept in `compared`, after which any other save can be compared
+    /// with it on a `SaveDiffScreen`.
+    fn compare_ui(
+        ui: &mut Ui,
+        state: &AppState,
+        id: SaveId,
+        compared: &mut Option<SaveId>,
+        requests: &mut Vec<Request>,
+    ) {
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| match *compared {
+            Some(other) if other != id => {
+                if ui.button(format!("Compare with save {}", *other)).clicked() {
+                    requests.push(Request::ScreenManager(
+                        ScreenManagerRequest::AddAndSelectScreen {
+                            screen: Box::new(SaveDiffScreen::new(other, id)),
+                            parent_id: state.screen_manager.current_id(),
+                        },
+                    ));
+                }
+                if ui.button("Stop comparing").clicked() {
+                    *compared = None;
+                }
+            }
+            Some(_) => {
+                let _: Response = ui.label("Select another save to compare this one with.");
+                if ui.button("Stop comparing").clicked() {
+                    *compared = None;
+                }
+            }
+            None => {
+                if ui
+                    .button("Compare")
+                    .on_hover_text("Select another save afterwards, to see what changed between them.")
+                    .clicked()
+                {
+                    *compared = Some(id);
+                }
+            }
+        });
+    }
+
+    /// Render the editor of the `SaveMetadata` being edited.
+    ///
+    /// Returns the `SaveRequest` to send if the changes were saved, and whether it is still being
+    /// edited.
+    fn editor_ui(
+        ui: &mut Ui,
+        id: SaveId,
+        metadata: &mut SaveMetadata,
+    ) -> (Option<SaveRequest>, bool) {
+        let _: Response = ui.strong(format!("Details of save {}", *id));
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            let _: Response = ui.label("Name:");
+            let _: Response = ui.text_edit_singleline(&mut metadata.name);
+            if let Some(tag) = Self::tag_toggles_ui(ui, |tag: ColorTag| metadata.has_tag(tag)) {
+                metadata.toggle_tag(tag);
+            }
+            let _: Response = ui.checkbox(&mut metadata.pinned, "Pinned");
+        });
+        let _: Response = ui.label("Notes:");
+        let _: Response = ui.text_edit_multiline(&mut metadata.notes);
+
+        let mut saved: bool = false;
+        let mut cancelled: bool = false;
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            saved = ui.button("Save details").clicked();
+            cancelled = ui.button("Cancel").clicked();
+        });
+        (
+            saved.then(|| SaveRequest::SetMetadata {
+                id,
+                metadata: metadata.clone(),
+            }),
+            !saved && !cancelled,
+        )
+    }
+
+    /// Draw the tree. Returns the save that was clicked, if any.
+    ///
+    /// Saves that don't match the filter are dimmed, unless it is empty.
+    fn tree_ui(
+        ui: &mut Ui,
+        playthrough: &Playthrough,
+        selected: Option<SaveId>,
+        filter: &SaveFilter,
+    ) -> Option<SaveId> {
+        let layout: SaveTreeLayout = SaveTreeLayout::new(playthrough);
+        let mut clicked: Option<SaveId> = None;
+
+        for &edge in &layout.edges {
+            let _: ShapeIdx = ui
+                .painter()
+                .line_segment(edge, ui.visuals().widgets.noninteractive.fg_stroke);
+        }
+
+        for &(id, rect) in &layout.nodes {
+            let Ok(entry) = playthrough.get_save(id) else {
+                continue;
+            };
+            let response: Response = ui.allocate_rect(rect, Sense::click());
+            if response.clicked() {
+                clicked = Some(id);
+            }
+
+            let (fill, text_color): (Color32, Color32) = if !filter.is_empty() && !filter.matches(entry.metadata()) {
+                (
+                    ui.visuals().extreme_bg_color,
+                    ui.visuals().weak_text_color(),
+                )
+            } else {
+                match *entry {
+                    SaveEntry::Active(_) => (
+                        ui.visuals().widgets.inactive.bg_fill,
+                        ui.visuals().strong_text_color(),
+                    ),
+                    SaveEntry::Trashed(_) => (ui.visuals().faint_bg_color, ui.visuals().text_color()),
+                    SaveEntry::Deleted(_) => (ui.visuals().faint_bg_color, ui.visuals().weak_text_color()),
+                }
+            };
+            let stroke: Stroke = if selected == Some(id) {
+                ui.visuals().selection.stroke
+            } else if response.hovered() {
+                ui.visuals().widgets.hovered.bg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+
+            let _: ShapeIdx = ui
+                .painter()
+                .rect(rect, 6.0, fill, stroke, StrokeKind::Inside);
+            let _: Rect = ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                format!(
+                    "{}\n{}\n{}",
+                    Self::save_title(id, entry),
+                    DisplayDuration(entry.get_playtime()),
+                    DisplayTime(entry.get_birth_time())
+                ),
+                FontId::proportional(13.0),
+                text_color,
+            );
+            let mut dot: Pos2 = rect.left_top() + vec2(10.0, 10.0);
+            for tag in &entry.metadata().tags {
+                let _: ShapeIdx = ui.painter().circle_filled(dot, 4.0, tag.color());
+                dot += vec2(12.0, 0.0);
+            }
+        }
+
+        clicked
+    }
+}
+
+impl Screen for SaveTreeScreen {
+    fn title(&self) -> String {
+        "Saves".to_owned()
+    }
+
+    fn ui(
+        &mut self,
+        ui: &mut Ui,
+        state: &AppState,
+    ) -> Vec<Request> {
+        let mut requests: Vec<Request> = Vec::new();
+
+        let _: InnerResponse<()> = ui.horizontal(|ui: &mut Ui| {
+            if ui.button("Back").clicked() {
+                requests.push(Request::ScreenManager(ScreenManagerRequest::Back));
+                requests.push(Request::Playthrough(PlaythroughRequest::Refresh));
+            }
+            if ui.button("Reset view").clicked() {
+                self.scene_rect = Rect::ZERO;
+            }
+            if ui
+                .button("Compact")
+                .on_hover_text("Remove the branches in which every save was deleted and can't be restored anymore.")
+                .clicked()
+            {
+                requests.push(Request::Save(SaveRequest::Prune));
+            }
+            if let Some(ref playthrough) = state.open_playthrough {
+                let settings: PlaythroughSettings = playthrough.settings();
+                let _: Response = ui.heading(playthrough.name());
+                let _: Response = ui.label(format!(
+                    "{}{}, seed {}",
+                    settings.difficulty.name(),
+                    if settings.permadeath {
+                        ", permadeath"
+                    } else {
+                        ""
+                    },
+                    settings.seed
+                ));
+            }
+            if let Some(ref archive_status) = state.archive_status {
+                let _: Response = ui.label(archive_status);
+            }
+            if let Some(ref save_error) = state.save_error {
+                let _: Response = ui.colored_label(ui.visuals().error_fg_color, save_error);
+            }
+            if let Some(report) = state.prune_report {
+                let _: Response = ui.label(format!(
+                    "Compacting removed {} deleted saves, with {} of playtime.",
+                    report.removed,
+                    DisplayDuration(report.dropped_playtime)
+                ));
+            }
+        });
+
+        let Some(ref playthrough) = state.open_playthrough else {
+            if let Some(ref open_error) = state.open_error {
+                let _: Response = ui.colored_label(ui.visuals().error_fg_color, open_error);
+            } else {
+                let _: Response = ui.label("Loading...");
+            }
+            return requests;
+        };
+
+        Self::search_ui(ui, playthrough, &mut self.filter, &mut self.selected);
+        if let Some((id, entry, playtime)) = self.selected.and_then(|id: SaveId| {
+            Some((
+                id,
+                playthrough.get_save(id).ok()?,
+                playthrough.playtime_with_ancestors(id).ok()?,
+            ))
+        }) {
+            Self::selection_ui(
+                ui,
+                state,
+                id,
+                entry,
+                playtime,
+                &mut self.editing,
+                &mut requests,
+            );
+            Self::compare_ui(ui, state, id, &mut self.compared, &mut requests);
+        } else {
+            self.selected = None;
+            let _: Response = ui.label("Click a save to select it. Drag to pan, and scroll while holding Ctrl to zoom.");
+        }
+        if let Some((id, mut metadata)) = self.editing.take() {
+            let _: Response = ui.separator();
+            let (request, still_editing): (Option<SaveRequest>, bool) = Self::editor_ui(ui, id, &mut metadata);
+            requests.extend(request.map(Request::Save));
+            self.editing = still_editing.then_some((id, metadata));
+        }
+        let _: Response = ui.separator();
+
+        let scene: InnerResponse<Option<SaveId>> = Scene::new()
+            .zoom_range(0.1..=4.0)
+            .show(ui, &mut self.scene_rect, |ui: &mut Ui| {
+                Self::tree_ui(ui, playthrough, self.selected, &self.filter)
+            });
+        if let Some(clicked) = scene.inner {
+            self.selected = Some(clicked);
+        }
+        requests
+    }
+}
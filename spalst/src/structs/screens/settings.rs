@@ -2,30 +2,83 @@
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
 use crate::{
-    enums::{Request, ScreenManagerRequest},
-    structs::App,
+    enums::{Request, ScreenManagerRequest, TransitionKind},
+    structs::{AppState, TransitionSettings},
     traits::Screen,
 };
+use derive_new::new;
 use egui::{InnerResponse, Response, Ui};
 
 /// Settings screen.
-#[derive(Debug)]
-pub struct SettingsScreen;
+///
+/// Changes are committed once the screen is left (see `Screen::on_exit()`).
+#[derive(Debug, new)]
+pub struct SettingsScreen {
+    /// The `TransitionSettings` being edited. Copied from the `ScreenManager` when the screen is
+    /// first shown.
+    #[new(default)]
+    transitions: Option<TransitionSettings>,
+    /// Whether the breadcrumb bar is shown, as being edited. Copied from the `ScreenManager` when
+    /// the screen is first shown.
+    #[new(default)]
+    show_breadcrumbs: Option<bool>,
+}
+
+impl SettingsScreen {
+    /// The name of the `Route` that leads to this screen.
+    pub const ROUTE: &'static str = "settings";
+}
 
 impl Screen for SettingsScreen {
+    fn title(&self) -> String {
+        "Settings".to_owned()
+    }
+
     fn ui(
-        &self,
+        &mut self,
         ui: &mut Ui,
-        _app: &App,
+        state: &AppState,
     ) -> Vec<Request> {
         let mut result: Vec<Request> = Vec::new();
+        let transitions: &mut TransitionSettings = self
+            .transitions
+            .get_or_insert_with(|| state.screen_manager.transition_settings());
+        let show_breadcrumbs: &mut bool = self
+            .show_breadcrumbs
+            .get_or_insert_with(|| state.screen_manager.show_breadcrumbs());
         let _: InnerResponse<()> = ui.vertical_centered(|ui: &mut Ui| {
             let _: Response = ui.heading("Settings");
-            let _: Response = ui.label("Feature not implemented yet.");
+            let _: Response = ui.checkbox(show_breadcrumbs, "Show breadcrumbs");
+            let _: Response = ui.label("Screen transitions:");
+            let _: InnerResponse<()> = ui.add_enabled_ui(!transitions.reduce_motion, |ui: &mut Ui| {
+                for kind in TransitionKind::ALL {
+                    let _: Response = ui.radio_value(&mut transitions.kind, kind, kind.name());
+                }
+            });
+            let _: Response = ui
+                .checkbox(&mut transitions.reduce_motion, "Reduce motion")
+                .on_hover_text("Switch screens instantly, whatever transition is chosen.");
             if ui.button("Back").clicked() {
                 result.push(Request::ScreenManager(ScreenManagerRequest::Back));
             }
         });
         result
     }
+
+    /// Commit the changed settings.
+    fn on_exit(
+        &mut self,
+        requests: &mut Vec<Request>,
+    ) {
+        if let Some(transitions) = self.transitions.take() {
+            requests.push(Request::ScreenManager(
+                ScreenManagerRequest::SetTransitionSettings(transitions),
+            ));
+        }
+        if let Some(show) = self.show_breadcrumbs.take() {
+            requests.push(Request::ScreenManager(
+                ScreenManagerRequest::SetShowBreadcrumbs(show),
+            ));
+        }
+    }
 }
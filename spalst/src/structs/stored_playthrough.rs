@@ -0,0 +1,26 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::{PlaythroughSettings, StoredSave};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A `Playthrough` in the form it is written to disk.
+///
+/// See `Playthrough::to_bytes()` and `Playthrough::from_bytes_migrated()` for the whole file format.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StoredPlaythrough {
+    /// The name of the `Playthrough`.
+    pub name: String,
+    /// The rules the `Playthrough` was created with.
+    pub settings: PlaythroughSettings,
+    /// The last time any `Save` of the `Playthrough` was played, if ever.
+    pub last_played: Option<DateTime<Utc>>,
+    /// The index of the `SaveId` of the root `Save`, if one was made.
+    pub root_save: Option<usize>,
+    /// Every `SaveEntry` that wasn't removed from the arena.
+    ///
+    /// Ordered so that every entry comes after its parent, and siblings are kept in their original
+    /// order.
+    pub saves: Vec<StoredSave>,
+}
@@ -0,0 +1,73 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::enums::SaveEntry;
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A `SaveEntry` in the form it is written to disk.
+///
+/// The arena of a `Playthrough` can't be written as-is, so every node is flattened into one of
+/// these. Its position in the tree is kept through `id` and `parent`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StoredSave {
+    /// The index of the `SaveId` this entry had in its `Playthrough`.
+    ///
+    /// Indexes start at 1, same as `indextree::NodeId`.
+    pub id: usize,
+    /// The index of the `SaveId` of the parent of this entry, if it has one.
+    pub parent: Option<usize>,
+    /// The checksum of `entry` when it was stored (see `StoredSave::checksum_of()`).
+    pub checksum: u64,
+    /// The entry itself.
+    pub entry: SaveEntry,
+}
+
+impl StoredSave {
+    /// Constructs a new `StoredSave` instance, with the checksum of its entry.
+    ///
+    /// # Errors
+    /// If serializing the entry fails.
+    pub fn try_new(
+        id: usize,
+        parent: Option<usize>,
+        entry: SaveEntry,
+    ) -> Result<Self> {
+        Ok(Self {
+            id,
+            parent,
+            checksum: Self::checksum_of(&serde_json::to_value(&entry)?)?,
+            entry,
+        })
+    }
+
+    /// Check whether `entry` still matches the checksum it was stored with.
+    ///
+    /// # Errors
+    /// If serializing the entry fails.
+    pub fn has_valid_checksum(&self) -> Result<bool> {
+        Ok(Self::checksum_of(&serde_json::to_value(&self.entry)?)? == self.checksum)
+    }
+
+    /// Compute the checksum of an entry, given as JSON.
+    ///
+    /// This is the 64-bit FNV-1a hash of the compact JSON. The keys of JSON objects are sorted, so
+    /// the same entry always hashes the same, whether it was serialized from a `SaveEntry` or
+    /// edited as JSON by a `Migration`.
+    ///
+    /// # Errors
+    /// If serializing the JSON fails.
+    pub fn checksum_of(entry: &Value) -> Result<u64> {
+        /// The FNV-1a offset basis for 64-bit hashes.
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        /// The FNV-1a prime for 64-bit hashes.
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        Ok(serde_json::to_vec(entry)?
+            .into_iter()
+            .fold(OFFSET_BASIS, |hash: u64, byte: u8| {
+                (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+            }))
+    }
+}
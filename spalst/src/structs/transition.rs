@@ -0,0 +1,44 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::{
+    enums::{NavigationDirection, TransitionKind},
+    structs::ScreenId,
+};
+use derive_new::new;
+use egui::{Context, Id};
+
+/// An animated switch from one `Screen` to the current one in `ScreenManager`, which is in
+/// flight. Both `Screen`s are rendered until it finishes.
+#[derive(Clone, Copy, Debug, new)]
+pub struct Transition {
+    /// The `Screen` that is switched away from.
+    pub from_id: ScreenId,
+    /// How the switch is animated.
+    pub kind: TransitionKind,
+    /// Which way the switch went.
+    pub direction: NavigationDirection,
+    /// Which transition of the `ScreenManager` this is, so that every one has its own animation.
+    number: u64,
+    /// Whether the animation was started.
+    #[new(default)]
+    started: bool,
+}
+
+impl Transition {
+    /// Advance the animation (driven by the animation time of the style of `ctx`), and return how
+    /// far it is, from 0 to 1.
+    pub fn progress(
+        &mut self,
+        ctx: &Context,
+    ) -> f32 {
+        let id: Id = Id::new(("transition", self.number));
+        let animation_time: f32 = ctx.global_style().animation_time;
+        if !self.started {
+            // A new animation starts at its target, so it is set to 0 before going towards 1.
+            let _: f32 = ctx.animate_value_with_time(id, 0.0, animation_time);
+            self.started = true;
+        }
+        ctx.animate_value_with_time(id, 1.0, animation_time)
+    }
+}
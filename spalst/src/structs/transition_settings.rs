@@ -0,0 +1,26 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::enums::TransitionKind;
+use derive_new::new;
+
+/// How `ScreenManager` animates switching `Screen`s, as chosen by the user.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, new)]
+pub struct TransitionSettings {
+    /// The kind of transition to use.
+    pub kind: TransitionKind,
+    /// Whether to turn every transition off, whatever `kind` is.
+    pub reduce_motion: bool,
+}
+
+impl TransitionSettings {
+    /// Get the `TransitionKind` that is actually used, which is `TransitionKind::None` if motion
+    /// is reduced.
+    pub const fn effective_kind(self) -> TransitionKind {
+        if self.reduce_motion {
+            TransitionKind::None
+        } else {
+            self.kind
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+//! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
+
+use crate::structs::Save;
+use chrono::{DateTime, TimeDelta, Utc};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// A deleted save that can still be restored.
+///
+/// Deleting a `Save` doesn't throw its game data away right away. Instead, it is kept in here for
+/// the trash retention period (see `PlaythroughStore`), so accidental deletes can be undone with
+/// `Playthrough::restore_save()`. Once the period expires, it is compacted into a `DeletedSave`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TrashedSave {
+    /// The deleted `Save`, along with all of its data.
+    save: Save,
+    /// When the `Save` was deleted.
+    deleted_at: DateTime<Utc>,
+}
+
+impl TrashedSave {
+    /// Put this `Save` into the trash, as if it was deleted just now.
+    pub fn new(save: Save) -> Self {
+        Self {
+            save,
+            deleted_at: Utc::now(),
+        }
+    }
+
+    /// Get the deleted `Save`.
+    pub const fn save(&self) -> &Save {
+        &self.save
+    }
+
+    /// Get the deleted `Save`, mutably.
+    pub const fn save_mut(&mut self) -> &mut Save {
+        &mut self.save
+    }
+
+    /// Get the time when the `Save` was deleted.
+    pub const fn deleted_at(&self) -> DateTime<Utc> {
+        self.deleted_at
+    }
+
+    /// Get the time when the retention period of this `TrashedSave` expires, after which it can no
+    /// longer be restored.
+    pub fn expires_at(
+        &self,
+        retention: Duration,
+    ) -> DateTime<Utc> {
+        TimeDelta::from_std(retention)
+            .ok()
+            .and_then(|retention: TimeDelta| self.deleted_at.checked_add_signed(retention))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Check whether the retention period of this `TrashedSave` has expired at this time.
+    pub fn is_expired(
+        &self,
+        retention: Duration,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.expires_at(retention) <= now
+    }
+}
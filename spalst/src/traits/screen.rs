@@ -1,16 +1,75 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 //! SPDX-FileCopyrightText: Stefan Rankovic <stefi.rankovic@proton.me>
 
-use crate::{enums::Request, structs::App};
-use core::fmt::Debug;
+use crate::{enums::Request, structs::AppState};
+use core::{any::Any, fmt::Debug};
 use egui::Ui;
 
 /// A screen that renders UI within the application.
-pub trait Screen: Debug + Send + Sync {
+pub trait Screen: Any + Debug + Send + Sync {
+    /// The name of the screen, as shown to the user in the breadcrumb bar and the window title.
+    fn title(&self) -> String;
+
     /// Render the screen.
+    ///
+    /// `state` is already locked while this is called, so it must not be locked again. Whatever
+    /// the screen needs to remember between frames (text being edited, selections and the like)
+    /// can be kept in the screen itself, since it is only dropped once it is removed from the
+    /// `ScreenManager`.
     fn ui(
-        &self,
+        &mut self,
         ui: &mut Ui,
-        app: &App,
+        state: &AppState,
     ) -> Vec<Request>;
+
+    /// Called when this screen becomes the current one for the first time, or again after it was
+    /// exited and is gone forward to.
+    ///
+    /// Like every lifecycle hook, it is called while `ScreenManagerRequest`s are handled, so it
+    /// can't look at the `AppState`. Instead, it can push `Request`s to `requests`, which are
+    /// handled right after the one that called it.
+    fn on_enter(
+        &mut self,
+        _requests: &mut Vec<Request>,
+    ) {
+    }
+
+    /// Called when this screen is left by going back from it.
+    fn on_exit(
+        &mut self,
+        _requests: &mut Vec<Request>,
+    ) {
+    }
+
+    /// Called when another screen becomes the current one while this one stays where it is, for
+    /// example when a screen is opened from it.
+    fn on_pause(
+        &mut self,
+        _requests: &mut Vec<Request>,
+    ) {
+    }
+
+    /// Called when this screen becomes the current one again, after it was paused.
+    fn on_resume(
+        &mut self,
+        _requests: &mut Vec<Request>,
+    ) {
+    }
+
+    /// Called with the result an overlay opened over this screen was closed with (see
+    /// `ScreenManager::close_overlay()`), like the `Confirmation` of a `ConfirmDialog`. It has to
+    /// be downcast to the type the overlay closes with.
+    fn on_overlay_result(
+        &mut self,
+        _result: Box<dyn Any + Send + Sync>,
+        _requests: &mut Vec<Request>,
+    ) {
+    }
+
+    /// Whether the active save is being played while this screen is shown.
+    ///
+    /// The `PlaytimeClock` only runs while such a screen is the current one.
+    fn is_gameplay(&self) -> bool {
+        false
+    }
 }